@@ -4,8 +4,8 @@ use eframe::Frame;
 use eframe::emath::Align;
 use egui::{CentralPanel, ComboBox, Context, Layout, RichText, ScrollArea};
 use egui_property_editor::{
-    EmptyStringIsNone, Property, PropertyEditor, ValidatedProperty, ValidationError, enum_property,
-    unit_enum_property,
+    DurationFormat, DurationProperty, EmptyStringIsNone, Orientation, Property, PropertyEditor,
+    StringProperty, ValidatedProperty, ValidationError, enum_property, unit_enum_property,
 };
 use std::fmt::Formatter;
 use std::time::Duration;
@@ -34,6 +34,7 @@ struct App {
     an_int: i32,
     another_thing: usize,
     a_bool: bool,
+    bool_changes: usize,
     a_duration: Duration,
     something_optional: Option<String>,
     optional_struct: Option<InnerThingWithDefault>,
@@ -111,6 +112,7 @@ impl Default for App {
             an_int: 123,
             another_thing: 0,
             a_bool: false,
+            bool_changes: 0,
             a_duration: Duration::from_secs(128),
             something_optional: None,
             optional_struct: None,
@@ -135,16 +137,28 @@ impl eframe::App for App {
                 // This is the basic way - when you embedded this in a ui group yourself, and/or do not care about element sizes.
                 // It is not the prettyest however.
                 let first_valid = PropertyEditor::new("editor left")
+                    // All three editors share their column widths, so the columns line up.
+                    .column_group("demo editors")
                     // Have a look at the doc for all functions like this
                     .stripes(true)
+                    // Long descriptions wrap at this width, instead of whatever space is left.
+                    .description_width(250.0)
+                    // Headlines can collapse their section, and a search bar can filter by name.
+                    // Both remember their state per editor id.
+                    .collapsible_headlines(true)
+                    .search_bar(true)
+                    // Up and down move between the properties, in addition to tab and shift-tab.
+                    .arrow_key_navigation(true)
                     .headline("Should be left")
                     // There are many ways to create a property.
                     // Most functions take an Into<Property>, so you can hand it things where there is a default implementation.
                     .named_property("A string", &mut self.some_string)
-                    .named_property("Some other String", &mut self.some_other_string)
+                    .named_property("Some other String", StringProperty::new(&mut self.some_other_string).multiline(true).hint_text("Can span lines"))
                     .headline("Another headline")
                     .property(("C String", &mut self.c,"Strings the C. Also a very very very very very very long description, to show that wrapping in the last column works. How much text do i need for this? I don't know."))
-                    .named_property("D String", &mut self.d)
+                    // Names can have a tooltip. With `description_as_tooltip(true)`, descriptions would also become one.
+                    // The widget can also get its own font, here monospace.
+                    .named_property("D String", Property::from(&mut self.d).name_tooltip("I am the d string").monospace())
                     // Validation is done with a callback. As of writing this there aren't too many variants for the ValidationError.
                     // This is because you quite likely will want to write custom messages anyway.
                     .named_property(
@@ -176,9 +190,12 @@ impl eframe::App for App {
                     ui.strong("Same thing again, but centered");
                     // Same things as before...
                     let second_valid = PropertyEditor::new("editor center")
+                        .column_group("demo editors")
                         .stripes(true)
                         .outer_border(true)
                         .min_col_width(Some(125.0))
+                        // Names and descriptions are centered against tall widgets, like the multiline text edit below.
+                        .cell_valign(Align::Center)
                         .headline("Should be left")
                         .named_property("A string", &mut self.some_string)
                         .named_property("Some other String", &mut self.some_other_string)
@@ -196,7 +213,8 @@ impl eframe::App for App {
                         .named_property("F String", &mut self.f)
                         .headline("Numbers work as well, and can be validated")
                         // ... with a few more types ...
-                        .named_property("An int", &mut self.an_int)
+                        // Any property can get a prefix or suffix label, for example a unit.
+                        .named_property("An int", Property::from(&mut self.an_int).suffix("px"))
                         .named_property(
                             "Another thing",
                             ValidatedProperty::new(&mut self.another_thing, |val| {
@@ -352,15 +370,41 @@ impl eframe::App for App {
                 ui.with_layout(Layout::top_down(Align::Max), |ui| {
                     ui.strong("And on the right ");
                     let _third_valid = PropertyEditor::new("editor right")
+                        .column_group("demo editors")
                         .stripes(true)
                         .outer_border(true)
+                        // Each column can get its own minimum width: a narrow name, and a wide widget column.
+                        .column_widths(Some(80.0), Some(200.0), None)
+                        // A line below each headline separates the sections.
+                        .headline_rule(true)
                         .headline("Should still be on the left")
                         .named_property("A string", &mut self.some_string)
                         .named_property("Some other String", &mut self.some_other_string)
-                        .headline("Another headline")
-                        .named_property("A Bool", &mut self.a_bool)
+                        // Groups draw a border around everything until end_group, and start with a headline.
+                        .begin_group("A group")
+                        // on_change is called whenever the value was edited
+                        .named_property(
+                            "A Bool",
+                            Property::from(&mut self.a_bool)
+                                // An icon in front of the name. Images work as well.
+                                .icon('⚙')
+                                .on_change(|| self.bool_changes += 1),
+                        )
+                        .end_group()
                         .show(ui);
+                    ui.label(format!("The bool changed {} times.", self.bool_changes));
                 });
+                ui.separator();
+                // A horizontal editor draws "name: widget" inline, which is handy for small toolbars.
+                PropertyEditor::new("editor toolbar")
+                    .orientation(Orientation::Horizontal)
+                    .named_property("Int", &mut self.an_int)
+                    .named_property("Bool", &mut self.a_bool)
+                    .named_property(
+                        "Duration",
+                        DurationProperty::new(&mut self.a_duration).format(DurationFormat::Clock),
+                    )
+                    .show(ui);
             });
         });
     }
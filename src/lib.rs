@@ -24,14 +24,51 @@
 //!         .show(ui);
 //! # });
 //! ```
-use egui::emath::Align;
+//!
+//! # Features
+//!
+//!   * `serde`: The persistent state of each editor (sizing, section collapse states, search text) becomes serializable.
+//!     If your app enables `egui/persistence`, this state is then saved to disk alongside the rest of egui's memory, and
+//!     reopening a window restores what the user had. Without this feature, the state only lives as long as the egui `Context`.
+//!     It also adds `Property::exported`, whose values `PropertyEditor::to_json` and `PropertyEditor::apply_json` save and load.
+//!   * `chrono`: `NaiveDate`, `NaiveTime` and `NaiveDateTime` convert into a `Property`, edited with a `DragValue` per field.
+//!     Dates that do not exist, such as the 30th of February, fail validation.
+//!
+//! Note that the collapse state is kept per section and never pruned, so an editor that creates many (or changing) sections will
+//! grow its stored state by a few bytes for every section it has ever shown.
+use egui::collapsing_header::paint_default_icon;
+use egui::emath::{Align, Numeric};
+use egui::response::Flags;
+use egui::text::{LayoutJob, TextFormat};
+use egui::text_selection::LabelSelectionState;
 use egui::{
-    Align2, Color32, Context, Direction, DragValue, FontId, FontSelection, Grid, Id, Layout, Rect,
-    Response, Sense, Stroke, StrokeKind, TextEdit, TextWrapMode, Ui, UiBuilder, Vec2, Widget,
-    WidgetText,
+    Align2, Button, Checkbox, Color32, ComboBox, Context, CursorIcon, Direction, DragAndDrop,
+    DragValue, FontId, FontSelection, Galley, Grid, Id, Image, ImageSource, Key, Label, Layout,
+    Modifiers, OpenUrl, Popup, PopupCloseBehavior, Pos2, Rect, Response, ScrollArea, Sense, Shape,
+    Slider, SliderClamping, Stroke, StrokeKind, TextEdit, TextStyle, TextWrapMode, Ui, UiBuilder,
+    UiStackInfo, Vec2, Widget, WidgetInfo, WidgetText, WidgetType,
 };
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::time::Duration;
+use std::hash::Hash;
+use std::net::{IpAddr, SocketAddr};
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroUsize, Wrapping,
+};
+use std::ops::{BitAnd, BitOr, Not, Range, RangeInclusive};
+#[cfg(feature = "serde")]
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{
+    AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8, AtomicU16,
+    AtomicU32, AtomicU64, AtomicUsize, Ordering,
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// A property editor is a builder that combines multiple `Property`s and drawing-related settings.
 ///
@@ -39,38 +76,215 @@ use std::time::Duration;
 pub struct PropertyEditor<'a> {
     /// The id salt to make memory persistent.
     id: Id,
-    /// If any added properties have a description, this becomes true and indicates we need the thrid column.
-    show_descriptions: bool,
+    /// The settings handed to every property while drawing.
+    settings: EditorSettings,
     /// If the grid stripes are shown.
     show_stripes: bool,
+    /// The color of the grid stripes, instead of the faint background color of the theme.
+    stripe_color: Option<Color32>,
     /// If the whole thing gets an outer border.
     group_all: bool,
     /// If this is Some, the grids get a minimum col width
     min_column_width: Option<f32>,
+    /// If this is Some, the grid columns do not grow wider than this
+    max_column_width: Option<f32>,
     /// The spacing of the headline entry. Might not be followed 100%.
     headline_spacing: Vec2,
+    /// The spacing between widgets and rows, instead of the one of the egui style.
+    item_spacing: Option<Vec2>,
+    /// The minimum height of each grid row.
+    row_height: Option<f32>,
+    /// If this is Some, the properties scroll once they are taller than this
+    max_height: Option<f32>,
+    /// If headlines can be clicked to collapse the section below them.
+    collapsible_headlines: bool,
+    /// If a separator line is drawn below each headline.
+    headline_rule: bool,
+    /// If a search field is shown above the properties.
+    show_search_bar: bool,
+    /// If the up and down arrow keys move focus between properties.
+    arrow_key_navigation: bool,
+    /// The id of the column group this editor shares its column widths with.
+    column_group: Option<Id>,
+    /// How many properties are placed next to each other, see `PropertyEditor::pairs_per_row`.
+    pairs_per_row: usize,
     /// The list of entries used by this editor.
     entries: Vec<EditorLine<'a>>,
+    /// The number of top level properties seen so far while drawing, see `PropertyEditor::show_with_active`.
+    property_index: Cell<usize>,
+    /// The index of the property with a focused widget, if any.
+    focused_property: Cell<Option<usize>>,
+    /// The index of the property with a hovered widget, if any.
+    hovered_property: Cell<Option<usize>>,
+    /// If properties can be reordered with drag handles.
+    reorderable: bool,
+    /// The index of the property shown at each position, see `PropertyEditor::reorderable`. Empty while not reordered.
+    order: Vec<usize>,
+    /// The section of each property, counted by the headlines and group borders in front of it.
+    property_sections: Vec<usize>,
+    /// The index and rect of each property drawn, for dropping dragged properties.
+    row_rects: RefCell<Vec<(usize, Rect)>>,
+    /// Validations across properties, run after the properties are drawn, see `PropertyEditor::validate_all`.
+    validators: RefCell<Vec<Box<ValidateFn<'a>>>>,
+    /// The row id and main widget response of each drawn property that passed its own validation, by property index.
+    validated_rows: RefCell<HashMap<usize, (Id, Response)>>,
+    /// The number of top level properties that changed while drawing, see `PropertyEditor::show_with_changes`.
+    changed_count: Cell<usize>,
+    /// Called with the response of each row, see `PropertyEditor::on_row`.
+    on_row: Option<RefCell<Box<RowFn<'a>>>>,
+    /// If the editor discards passes to find its size, see `PropertyEditor::auto_size`.
+    auto_size: bool,
+    /// The indices of the properties that differ from their original, see `PropertyEditor::show_with_dirty`.
+    dirty_properties: RefCell<Vec<usize>>,
+    /// Applied to the grid before it is shown, see `PropertyEditor::grid_config`.
+    grid_config: Option<Box<GridFn<'a>>>,
 }
 
+/// The callback type of `PropertyEditor::on_row`.
+pub type RowFn<'a> = dyn FnMut(usize, &Response) + 'a;
+
+/// The callback type of `PropertyEditor::grid_config`.
+pub type GridFn<'a> = dyn FnOnce(Grid) -> Grid + 'a;
+
+/// The callback type of `PropertyEditor::validate_all`.
+pub type ValidateFn<'a> = dyn FnMut() -> Vec<(usize, ValidationError)> + 'a;
+
 impl<'a> PropertyEditor<'a> {
     /// Create a new property editor, using `id_source` as a salt for the persistent id.
     pub fn new(id_source: impl Into<Id>) -> Self {
         Self {
             id: id_source.into(),
-            show_descriptions: false,
+            settings: EditorSettings::default(),
             show_stripes: false,
+            stripe_color: None,
             group_all: false,
             min_column_width: None,
+            max_column_width: None,
             headline_spacing: Vec2::new(0.0, 5.0),
+            item_spacing: None,
+            row_height: None,
+            max_height: None,
+            collapsible_headlines: false,
+            headline_rule: false,
+            show_search_bar: false,
+            arrow_key_navigation: false,
+            column_group: None,
+            pairs_per_row: 1,
             entries: vec![],
+            property_index: Cell::new(0),
+            focused_property: Cell::new(None),
+            hovered_property: Cell::new(None),
+            reorderable: false,
+            order: vec![],
+            property_sections: vec![],
+            row_rects: RefCell::new(vec![]),
+            validators: RefCell::new(vec![]),
+            validated_rows: RefCell::new(HashMap::new()),
+            changed_count: Cell::new(0),
+            on_row: None,
+            grid_config: None,
+            auto_size: true,
+            dirty_properties: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Create a new, empty property editor with the same id and settings as this one, to build once and fill every frame.
+    ///
+    /// Properties cannot be drawn twice, since they borrow the values they edit and are consumed by `show`.
+    /// The settings can be kept though: configure a `PropertyEditor<'static>` once, keep it around, and add this frame's
+    /// properties to a copy of it. Only the settings are copied, not the content, such as properties and headlines,
+    /// and not what is tied to it, such as `validate_all`, `on_row` and `grid_config`. One-shot requests, such as
+    /// `revert_all` and `accept_all`, are not copied either, so they are not repeated every frame.
+    ///
+    /// ```
+    /// # use egui_property_editor::PropertyEditor;
+    /// struct App {
+    ///     editor: PropertyEditor<'static>,
+    ///     speed: f32,
+    /// }
+    ///
+    /// impl App {
+    ///     fn new() -> Self {
+    ///         let editor = PropertyEditor::new("settings").stripes(true).outer_border(true);
+    ///         Self { editor, speed: 1.0 }
+    ///     }
+    ///
+    ///     fn ui(&mut self, ui: &mut egui::Ui) {
+    ///         self.editor.reuse().named_property("Speed", &mut self.speed).show(ui);
+    ///     }
+    /// }
+    /// ```
+    pub fn reuse<'b>(&self) -> PropertyEditor<'b> {
+        PropertyEditor {
+            settings: self.settings.reused(),
+            show_stripes: self.show_stripes,
+            stripe_color: self.stripe_color,
+            group_all: self.group_all,
+            min_column_width: self.min_column_width,
+            max_column_width: self.max_column_width,
+            headline_spacing: self.headline_spacing,
+            item_spacing: self.item_spacing,
+            row_height: self.row_height,
+            max_height: self.max_height,
+            collapsible_headlines: self.collapsible_headlines,
+            headline_rule: self.headline_rule,
+            show_search_bar: self.show_search_bar,
+            arrow_key_navigation: self.arrow_key_navigation,
+            column_group: self.column_group,
+            pairs_per_row: self.pairs_per_row,
+            reorderable: self.reorderable,
+            auto_size: self.auto_size,
+            ..PropertyEditor::new(self.id)
         }
     }
 
+    /// Create a new property editor with all properties of an iterator, see `PropertyEditor::properties`.
+    ///
+    /// ```
+    /// # use egui_property_editor::{Property, PropertyEditor};
+    /// # fn example(ui: &mut egui::Ui, values: &mut [(String, f32)]) {
+    /// PropertyEditor::from_iter(
+    ///     "settings",
+    ///     values.iter_mut().map(|(name, value)| Property::from(value).name(name.as_str())),
+    /// )
+    /// .show(ui);
+    /// # }
+    /// ```
+    pub fn from_iter<P: Into<Property<'a>>>(
+        id_source: impl Into<Id>,
+        properties: impl IntoIterator<Item = P>,
+    ) -> Self {
+        Self::new(id_source).properties(properties)
+    }
+
+    /// The size the editor with `id_source` took up when it was last shown, including the border.
+    ///
+    /// Use this to reserve space or pick a layout before showing it. Returns `None` until it was shown once.
+    /// Editors sharing an `id_source` share the size of the one shown last.
+    pub fn measured_size(ctx: &Context, id_source: impl Into<Id>) -> Option<Vec2> {
+        let persistent_id =
+            ctx.data(|d| d.get_temp::<Id>(Self::measured_size_id(id_source.into())))?;
+        let store = PropertyEditorStore::load(ctx, persistent_id)?;
+        Some(Vec2::new(store.last_width, store.last_height))
+    }
+
+    /// Where the persistent id of the editor with `id_source` is kept, see `PropertyEditor::measured_size`.
+    fn measured_size_id(id_source: Id) -> Id {
+        id_source.with("measured size")
+    }
+
     /// Show the property editor, consuming it.
     ///
     /// Will return `true` if all properties validated `Ok(())`, or `false` if one or more shows an error.
+    /// To keep the settings for the next frame, show a copy made with `PropertyEditor::reuse`.
     pub fn show(self, ui: &mut Ui) -> bool {
+        self.show_with_output(ui).valid
+    }
+
+    /// Show the property editor, consuming it, and return everything the other `show_with_*` functions return.
+    ///
+    /// Use this to get, for example, the dirty properties and the order of the same pass.
+    pub fn show_with_output(self, ui: &mut Ui) -> ShowOutput {
         // Always use this layout, but copy the alignment (so we can be centered as it pleases).
         ui.with_layout(Layout::top_down(ui.layout().horizontal_align()), |ui| {
             self.show_outer(ui)
@@ -78,8 +292,83 @@ impl<'a> PropertyEditor<'a> {
         .inner
     }
 
+    /// Show the property editor, consuming it, and return the widget responses of all properties.
+    ///
+    /// The responses are named after their property, in drawing order, and include nested properties.
+    /// Properties hidden by a collapsed headline or the search bar have no response.
+    /// The validation result is the same as the one of `show`.
+    pub fn show_with_responses(self, ui: &mut Ui) -> (bool, Vec<PropertyResponse>) {
+        let output = self.show_with_output(ui);
+        (output.valid, output.responses)
+    }
+
+    /// Show the property editor, consuming it, and return the index of the active property.
+    ///
+    /// The active property is the one with a hovered widget, or else the one with a focused widget.
+    /// Use this to show extended help for it, for example in a side panel.
+    /// The index counts the properties in the order they were added, without headlines and groups, and includes hidden ones.
+    /// Nested properties count as part of their top level property.
+    /// The validation result is the same as the one of `show`.
+    pub fn show_with_active(self, ui: &mut Ui) -> (bool, Option<usize>) {
+        let output = self.show_with_output(ui);
+        (output.valid, output.active)
+    }
+
+    /// Show the property editor, consuming it, and return how many properties changed in this pass.
+    ///
+    /// Use this to drive autosave and the like. Nested properties count as part of their top level property,
+    /// and `Property::debounce` delays the count like it delays `Property::on_change`.
+    /// The validation result is the same as the one of `show`.
+    pub fn show_with_changes(self, ui: &mut Ui) -> (bool, usize) {
+        let output = self.show_with_output(ui);
+        (output.valid, output.changed)
+    }
+
+    /// Show the property editor, consuming it, and return the indices of the properties that differ from their original value.
+    ///
+    /// Only `Tracked` properties know their original, see there. A property counts if any `Tracked` value in it differs,
+    /// including nested ones. The index counts the properties in the order they were added, without headlines and groups.
+    /// Properties hidden by a collapsed headline or the search bar are not drawn, and so not listed.
+    /// The validation result is the same as the one of `show`.
+    pub fn show_with_dirty(self, ui: &mut Ui) -> (bool, Vec<usize>) {
+        let output = self.show_with_output(ui);
+        (output.valid, output.dirty)
+    }
+
+    /// Show the property editor, consuming it, and return the order the user arranged the properties in.
+    ///
+    /// The order holds the index of the property shown at each position, where the index counts the properties in the
+    /// order they were added, without headlines and groups. It is the identity while `PropertyEditor::reorderable` is off.
+    /// The validation result is the same as the one of `show`.
+    pub fn show_with_order(self, ui: &mut Ui) -> (bool, Vec<usize>) {
+        let output = self.show_with_output(ui);
+        (output.valid, output.order)
+    }
+
+    /// Show the property editor directly in the current layout, consuming it, and return the rect it took up.
+    ///
+    /// Unlike `show`, this does not wrap the editor in its own top down layout, so it takes part in the spacing of `ui` directly.
+    /// The validation result is the same as the one of `show`.
+    ///
+    /// # Panics
+    /// If the layout of `ui` is not top down.
+    pub fn show_in_current_layout(self, ui: &mut Ui) -> (bool, Rect) {
+        assert_eq!(
+            ui.layout().main_dir,
+            Direction::TopDown,
+            "Property editor must be within a top down layout"
+        );
+        let output = self.show_outer(ui);
+        (output.valid, output.rect)
+    }
+
     /// The outer part of show, after things are assured to be in a vertical layout.
-    fn show_outer(mut self, ui: &mut Ui) -> bool {
+    ///
+    /// This relies on a few things to size itself over multiple passes:
+    ///   * `ui` is top down, so the editor is placed below the cursor, and its horizontal alignment is the cross alignment.
+    ///   * The persistent id is the same in every pass, so the width of the last pass can be looked up.
+    ///   * Nothing else is placed in `ui` while the editor is drawn, since the cursor is advanced past it afterward.
+    fn show_outer(mut self, ui: &mut Ui) -> ShowOutput {
         // should not happen, since show() assures a vertical layout. But who knows, and without all drawing dies.
         debug_assert_eq!(
             ui.layout().main_dir,
@@ -89,11 +378,18 @@ impl<'a> PropertyEditor<'a> {
 
         let persistent_id = ui.make_persistent_id(self.id);
         let mut store = PropertyEditorStore::load(ui.ctx(), persistent_id).unwrap_or_default();
+        // Arrow key navigation of the last pass. egui might have moved focus on its own in the meantime, so override that.
+        // If the focus did not move, the focused widget wanted the arrow keys for itself.
+        if let Some((from, to)) = store.pending_focus.take()
+            && ui.memory(|m| m.focused()) != Some(from)
+        {
+            ui.memory_mut(|m| m.request_focus(to));
+        }
         // the property editor is always left to right
         // however its position might vary depending on the layout.
         // The first pass must be left to right though, or we would not know the required size.
         let available_rect = ui.available_rect_before_wrap().intersect(ui.cursor());
-        let ui_rect = if store.first_pass {
+        let ui_rect = if store.first_pass || !self.auto_size {
             available_rect
         } else {
             match ui.layout().cross_align {
@@ -123,7 +419,26 @@ impl<'a> PropertyEditor<'a> {
         let inner_layout = Layout::top_down(Align::Min);
         let ui_builder = UiBuilder::new().max_rect(inner_rect).layout(inner_layout);
         let mut inner_ui = ui.new_child(ui_builder);
-        let validation_result = self.inner_ui(&mut inner_ui);
+        let mut group_store = self.column_group.map(|group| {
+            let mut group_store = ColumnGroupStore::load(ui.ctx(), group).unwrap_or_default();
+            group_store.begin_pass(ui.ctx().cumulative_pass_nr());
+            group_store.apply(&mut self.settings);
+            group_store
+        });
+        // changed column widths need a fresh sizing pass, or centered and right aligned editors would jump around.
+        if store.column_widths != self.settings.column_widths {
+            store.first_pass = true;
+            store.column_widths = self.settings.column_widths;
+        }
+        self.settings.widget_column_width = [
+            store.widest_widget,
+            self.settings.column_widths.widget.unwrap_or(0.0),
+            self.min_column_width.unwrap_or(0.0),
+        ]
+        .into_iter()
+        .fold(0.0, f32::max);
+        let validation_result = self.inner_ui(&mut inner_ui, &mut store);
+        store.widest_widget = self.settings.widest_widget.get();
 
         let final_inner_rect = inner_ui.min_rect();
         let final_rect = if self.group_all {
@@ -141,147 +456,1279 @@ impl<'a> PropertyEditor<'a> {
         ui.advance_cursor_after_rect(final_rect);
 
         // sizing pass?
-        if store.first_pass || store.last_width != final_rect.width() {
+        // Sub-pixel changes are ignored, and discards are capped, so a width that keeps oscillating cannot discard forever.
+        let width_changed = (store.last_width - final_rect.width()).abs() > WIDTH_TOLERANCE;
+        if !self.auto_size {
+            store.discards = 0;
+        } else if (store.first_pass || width_changed) && store.discards < MAX_DISCARDS {
+            store.discards += 1;
             ui.ctx().request_discard("Property editor size changed");
+        } else if !width_changed {
+            store.discards = 0;
         }
         store.first_pass = false;
         store.last_width = final_rect.width();
+        store.last_height = final_rect.height();
         store.store(ui.ctx(), persistent_id);
+        // the persistent id depends on the ui, so remember it for `measured_size`
+        ui.data_mut(|d| d.insert_temp(Self::measured_size_id(self.id), persistent_id));
+        if let (Some(group), Some(mut group_store)) = (self.column_group, group_store.take()) {
+            if group_store.merge(&self.settings) && self.auto_size {
+                ui.ctx()
+                    .request_discard("Property editor column group width changed");
+            }
+            group_store.store(ui.ctx(), group);
+        }
 
-        validation_result
+        ShowOutput {
+            valid: validation_result,
+            responses: self.settings.responses.take(),
+            rect: final_rect,
+            active: self.hovered_property.get().or(self.focused_property.get()),
+            order: self.order,
+            changed: self.changed_count.get(),
+            dirty: self.dirty_properties.take(),
+        }
     }
 
     /// Shows the inner ui (i.e inside a possible border) for this.
     ///
-    fn inner_ui(&mut self, ui: &mut Ui) -> bool {
+    fn inner_ui(&mut self, ui: &mut Ui, store: &mut PropertyEditorStore) -> bool {
+        // ui is a child of the editor, so this does not leak into the surrounding ui
+        if let Some(item_spacing) = self.item_spacing {
+            ui.spacing_mut().item_spacing = item_spacing;
+        }
+        if self.show_search_bar {
+            ui.add(TextEdit::singleline(&mut store.search).hint_text("Search"));
+        }
+        self.apply_order(store);
+        let validation_result = match self.max_height {
+            Some(max_height) => {
+                ScrollArea::vertical()
+                    .id_salt("property editor scroll area")
+                    .max_height(max_height)
+                    .show(ui, |ui| self.properties_ui(ui, store))
+                    .inner
+            }
+            None => self.properties_ui(ui, store),
+        };
+
+        validation_result || self.settings.read_only
+    }
+
+    /// Draws the properties, below the search bar and inside the scroll area if there is one.
+    fn properties_ui(&mut self, ui: &mut Ui, store: &mut PropertyEditorStore) -> bool {
+        let validation_result = match self.settings.orientation {
+            Orientation::Vertical => self.grid_ui(ui, store),
+            Orientation::Horizontal => self.inline_ui(ui, store),
+        };
+        let cross_validation_result = self.cross_validation_ui(ui);
+        self.commit_on_enter(ui);
+        self.navigate_focus(ui, store);
+        self.drop_dragged_property(ui, store);
+        validation_result && cross_validation_result
+    }
+
+    /// Runs the validations of `PropertyEditor::validate_all` once all properties are drawn, and outlines the flagged rows.
+    ///
+    /// Errors of properties that were not drawn still count, but are not shown. Returns if there were no errors.
+    fn cross_validation_ui(&self, ui: &Ui) -> bool {
+        let rows = self.validated_rows.take();
+        let mut shown = HashSet::new();
+        let mut valid = true;
+        for (index, error) in self.cross_errors() {
+            valid = false;
+            let Some((row_id, response)) = rows.get(&index) else {
+                continue;
+            };
+            let touched = !self.settings.validate_after_touch
+                || ui.data(|d| d.get_temp::<bool>(row_id.with("touched")).unwrap_or(false));
+            if touched && !self.settings.read_only && shown.insert(index) {
+                paint_error_overlay(ui, &self.settings, response, &error);
+            }
+        }
+        valid
+    }
+
+    /// The errors of all `PropertyEditor::validate_all` validations, for properties that exist.
+    fn cross_errors(&self) -> Vec<(usize, ValidationError)> {
+        let count = self
+            .entries
+            .iter()
+            .filter(|line| matches!(line, EditorLine::Property(_)))
+            .count()
+            .max(self.property_index.get());
+        self.validators
+            .borrow_mut()
+            .iter_mut()
+            .flat_map(|validate| validate())
+            .filter(|(index, _)| *index < count)
+            .collect()
+    }
+
+    /// Draws the properties as rows of a grid.
+    fn grid_ui(&mut self, ui: &mut Ui, store: &mut PropertyEditorStore) -> bool {
         let headline_width = ui.available_width() * 0.9;
+        // the grid only knows its width once it is done, so use the one of the last pass, without the border
+        let mut rule_width = if self.group_all {
+            store.last_width - 10.0
+        } else {
+            store.last_width
+        };
+        if self.max_height.is_some() {
+            // a scroll bar that is not floating takes up width of its own
+            rule_width -= ui.spacing().scroll.allocated_width();
+        }
+        self.settings.row_width = rule_width;
         let mut validation_result = true;
+        let mut section_collapsed = false;
         let mut entries = std::mem::take(&mut self.entries).into_iter().peekable();
-        let columns = if self.show_descriptions { 3 } else { 2 };
-        let mut grid = Grid::new(ui.next_auto_id())
-            .striped(self.show_stripes)
-            .num_columns(columns);
-        if let Some(width) = &self.min_column_width {
-            let max_width = ui.available_width() / columns as f32
-                - ui.spacing().item_spacing.x * columns.saturating_sub(1) as f32;
-            let width = width.min(max_width);
-            grid = grid.min_col_width(width);
+        let columns = if self.settings.has_description_column() {
+            3
+        } else {
+            2
+        };
+        let packed = self.pairs_per_row > 1;
+        if packed {
+            let gaps = ui.spacing().item_spacing.x * (self.pairs_per_row - 1) as f32;
+            self.settings.row_width = (rule_width - gaps) / self.pairs_per_row as f32;
+        }
+        // the columns of packed properties are striped themselves
+        let mut grid = self.new_grid(ui, ui.next_auto_id(), columns, !packed);
+        if let Some(grid_config) = self.grid_config.take() {
+            grid = grid_config(grid);
         }
+        // the top left corner of each open group
+        let mut groups = vec![];
+        let mut first_row = true;
         grid.show(ui, |ui| {
             while let Some(entry) = entries.next() {
+                let entry = match entry {
+                    EditorLine::GroupStart(title) => {
+                        groups.push(ui.cursor().min);
+                        EditorLine::Headline(title)
+                    }
+                    EditorLine::GroupEnd => {
+                        if let Some(top_left) = groups.pop() {
+                            Self::paint_group(ui, top_left, groups.len(), rule_width);
+                        }
+                        continue;
+                    }
+                    entry => entry,
+                };
                 match entry {
                     EditorLine::Headline(line) => {
-                        let text_pos = ui.cursor().min + Vec2::Y * ui.spacing().item_spacing.y;
-                        let galley =
-                            line.into_galley(ui, None, headline_width, FontSelection::Default);
+                        let section = Id::new(line.text()).value();
+                        // no gap above the first row, or the whole editor would be pushed down
+                        let top_spacing = if first_row {
+                            0.0
+                        } else {
+                            self.headline_spacing.y
+                        };
+                        let row_left = ui.cursor().min.x;
+                        let text_pos =
+                            ui.cursor().min + Vec2::new(self.headline_spacing.x, top_spacing);
+                        let icon_width = if self.collapsible_headlines {
+                            ui.spacing().icon_width + ui.spacing().icon_spacing
+                        } else {
+                            0.0
+                        };
+                        let galley = line.into_galley(
+                            ui,
+                            None,
+                            headline_width - icon_width - self.headline_spacing.x,
+                            FontSelection::Default,
+                        );
                         ui.allocate_response(
                             Vec2::X * 1.0
                                 + Vec2::Y
-                                    * (galley.rect.height() + 2.0 * ui.spacing().item_spacing.y),
+                                    * (galley.rect.height()
+                                        + top_spacing
+                                        + self.headline_spacing.y),
                             Sense::empty(),
                         );
                         ui.end_row();
-                        ui.painter()
-                            .galley(text_pos, galley.clone(), ui.visuals().text_color());
+                        if self.collapsible_headlines {
+                            let collapsed = store.collapsed_sections.entry(section).or_default();
+                            let header_rect = Rect::from_min_size(
+                                text_pos,
+                                Vec2::new(icon_width + galley.rect.width(), galley.rect.height()),
+                            );
+                            // not focusable, so tab skips headlines
+                            let response =
+                                ui.interact(header_rect, ui.id().with(section), Sense::CLICK);
+                            if response.clicked() {
+                                *collapsed = !*collapsed;
+                            }
+                            let icon_rect = Rect::from_center_size(
+                                Pos2::new(
+                                    text_pos.x + ui.spacing().icon_width * 0.5,
+                                    header_rect.center().y,
+                                ),
+                                Vec2::splat(ui.spacing().icon_width),
+                            );
+                            let openness = if *collapsed { 0.0 } else { 1.0 };
+                            paint_default_icon(ui, openness, &response.with_new_rect(icon_rect));
+                            section_collapsed = *collapsed;
+                        }
+                        if self.headline_rule && rule_width > 0.0 {
+                            let y =
+                                text_pos.y + galley.rect.height() + self.headline_spacing.y * 0.5;
+                            ui.painter().hline(
+                                row_left..=row_left + rule_width,
+                                y,
+                                ui.visuals().widgets.noninteractive.bg_stroke,
+                            );
+                        }
+                        Self::headline_text(ui, text_pos + Vec2::X * icon_width, galley);
+                    }
+                    EditorLine::Property(p) if packed => {
+                        let mut run = vec![*p];
+                        while let Some(EditorLine::Property(p)) =
+                            entries.next_if(|e| matches!(e, EditorLine::Property(_)))
+                        {
+                            run.push(*p);
+                        }
+                        validation_result &=
+                            self.packed_ui(ui, run, section_collapsed, &store.search, columns);
                     }
                     EditorLine::Property(p) => {
-                        validation_result &= p.draw(ui, self.show_descriptions);
+                        validation_result &=
+                            self.draw_entry(ui, *p, section_collapsed, &store.search);
                         // usually id agree, but this is more readable IMO.
                         #[allow(clippy::while_let_loop)]
                         loop {
                             match entries.next_if(|e| matches!(e, EditorLine::Property(_))) {
                                 Some(EditorLine::Property(p)) => {
-                                    validation_result &= p.draw(ui, self.show_descriptions);
+                                    validation_result &=
+                                        self.draw_entry(ui, *p, section_collapsed, &store.search);
                                 }
                                 _ => break,
                             }
                         }
                     }
+                    EditorLine::Spacer(height) => {
+                        if !section_collapsed {
+                            ui.allocate_space(Vec2::new(1.0, height));
+                            ui.end_row();
+                        }
+                    }
+                    // turned into a headline or skipped above
+                    EditorLine::GroupStart(_) | EditorLine::GroupEnd => unreachable!(),
                 }
+                first_row = false;
+            }
+            // groups that were never ended go to the end
+            while let Some(top_left) = groups.pop() {
+                Self::paint_group(ui, top_left, groups.len(), rule_width);
             }
         });
 
         validation_result
     }
 
-    /// Set to `true` if you want the inner grid to show stripes.
-    pub fn stripes(mut self, show_stripes: bool) -> Self {
-        self.show_stripes = show_stripes;
-        self
+    /// Creates a grid with `columns` columns, and the stripes, row height and column widths of this editor.
+    fn new_grid(&self, ui: &Ui, id: Id, columns: usize, striped: bool) -> Grid {
+        let striped = striped && self.show_stripes;
+        let mut grid = Grid::new(id).striped(striped).num_columns(columns);
+        if striped && let Some(color) = self.stripe_color {
+            // the same rows `Grid::striped` tints
+            grid = grid.with_row_color(move |row, _| (row % 2 == 1).then_some(color));
+        }
+        if let Some(row_height) = self.row_height {
+            grid = grid.min_row_height(row_height);
+        }
+        // all columns of packed properties share the width
+        let total_columns = columns * self.pairs_per_row;
+        let mut min_width = 0.0f32;
+        if let Some(width) = &self.min_column_width {
+            let max_width = ui.available_width() / total_columns as f32
+                - ui.spacing().item_spacing.x * total_columns.saturating_sub(1) as f32;
+            min_width = width.min(max_width);
+            grid = grid.min_col_width(min_width);
+        }
+        if let Some(max_width) = self.max_column_width {
+            // minimums win, so they never fight the maximum over the passes
+            let widths = &self.settings.column_widths;
+            let max_width = [widths.name, widths.widget, widths.description]
+                .into_iter()
+                .flatten()
+                .fold(max_width.max(min_width), f32::max);
+            grid = grid.max_col_width(max_width);
+        }
+        grid
     }
 
-    /// Set to `true` if you want to show a border around the whole property editor.
-    pub fn outer_border(mut self, outer_border: bool) -> Self {
-        self.group_all = outer_border;
-        self
+    /// Draws a run of properties next to each other, see `PropertyEditor::pairs_per_row`.
+    ///
+    /// Property `i` of the run goes into column `i % pairs_per_row`, each column a grid of its own.
+    /// They are all drawn into a single row of the editor grid.
+    fn packed_ui(
+        &self,
+        ui: &mut Ui,
+        run: Vec<Property<'a>>,
+        section_collapsed: bool,
+        search: &str,
+        columns: usize,
+    ) -> bool {
+        if section_collapsed {
+            // nothing is drawn, but the properties are still counted and validated
+            let mut valid = true;
+            for property in run {
+                valid &= self.draw_entry(ui, property, true, search);
+            }
+            return valid;
+        }
+        let pairs = self.pairs_per_row;
+        let start = self.property_index.get();
+        let count = run.len();
+        let mut packed: Vec<Vec<(usize, Property<'a>)>> = (0..pairs).map(|_| vec![]).collect();
+        for (i, property) in run.into_iter().enumerate() {
+            packed[i % pairs].push((i, property));
+        }
+        let id = ui.id().with("packed properties").with(start);
+        let mut valid = true;
+        ui.horizontal_top(|ui| {
+            for (column, properties) in packed.into_iter().enumerate() {
+                self.new_grid(ui, id.with(column), columns, true)
+                    .show(ui, |ui| {
+                        for (i, property) in properties {
+                            // drawn column by column, so the index has to be set for each property
+                            self.property_index.set(start + i);
+                            valid &= self.draw_entry(ui, property, false, search);
+                        }
+                    });
+            }
+        });
+        self.property_index.set(start + count);
+        ui.end_row();
+        valid
     }
 
-    /// Set the headline spacing, that is the distance of the headline to things.
-    pub fn headline_spacing(mut self, spacing: impl Into<Vec2>) -> Self {
-        self.headline_spacing = spacing.into();
-        self
+    /// Shows the text of a headline at `pos`, outside of the grid layout, so it can span all columns.
+    ///
+    /// It still behaves like a label, so it can be selected and copied if the egui style allows that,
+    /// and screen readers pick it up.
+    fn headline_text(ui: &Ui, pos: Pos2, galley: Arc<Galley>) {
+        let rect = Rect::from_min_size(pos, galley.size());
+        let selectable = ui.style().interaction.selectable_labels;
+        let sense = if selectable {
+            Sense::DRAG
+        } else {
+            Sense::hover()
+        };
+        let response = ui.interact(rect, ui.next_auto_id().with("headline"), sense);
+        response
+            .widget_info(|| WidgetInfo::labeled(WidgetType::Label, ui.is_enabled(), galley.text()));
+        if selectable {
+            LabelSelectionState::label_text_selection(
+                ui,
+                &response,
+                pos,
+                galley,
+                ui.visuals().text_color(),
+                Stroke::NONE,
+            );
+        } else {
+            ui.painter().galley(pos, galley, ui.visuals().text_color());
+        }
     }
 
-    /// If you set this to some, will provide the inner grid with a minimal col width.
-    /// Will look more aligned, but will of course also consume a bit more space.
-    pub fn min_col_width(mut self, min_col_width: Option<f32>) -> Self {
-        self.min_column_width = min_col_width;
-        self
+    /// Paints the border of a group, from the row at `top_left` to the current one.
+    ///
+    /// Like headline rules, this uses the width of the last pass. Nested groups are inset by their `depth`.
+    fn paint_group(ui: &Ui, top_left: Pos2, depth: usize, width: f32) {
+        if width <= 0.0 {
+            return;
+        }
+        let spacing = ui.spacing().item_spacing;
+        let rect = Rect::from_min_max(
+            top_left - spacing * 0.5,
+            Pos2::new(top_left.x + width, ui.cursor().min.y)
+                + Vec2::new(spacing.x, -spacing.y) * 0.5,
+        )
+        .shrink(depth as f32 * 2.0);
+        ui.painter()
+            .rect_stroke(rect, 2, ui.visuals().window_stroke, StrokeKind::Inside);
     }
 
-    /// Set to true to always show description column
-    pub fn show_descriptions(mut self, show_descriptions: bool) -> Self {
-        self.show_descriptions = show_descriptions;
-        self
+    /// Draws the properties inline, left to right, wrapping as needed.
+    ///
+    /// Headlines become separators with a label, and cannot be collapsed.
+    fn inline_ui(&mut self, ui: &mut Ui, store: &mut PropertyEditorStore) -> bool {
+        let mut validation_result = true;
+        ui.horizontal_wrapped(|ui| {
+            for (i, entry) in std::mem::take(&mut self.entries).into_iter().enumerate() {
+                match entry {
+                    EditorLine::Headline(line) | EditorLine::GroupStart(line) => {
+                        if i > 0 {
+                            ui.separator();
+                        }
+                        ui.label(line);
+                    }
+                    // there are no rows to put a border around
+                    EditorLine::GroupEnd => {}
+                    EditorLine::Spacer(width) => ui.add_space(width),
+                    EditorLine::Property(p) => {
+                        // ui.end_row() does nothing in a non-wrapping layout, so each property stays in one piece.
+                        validation_result &= ui
+                            .horizontal(|ui| self.draw_entry(ui, *p, false, &store.search))
+                            .inner;
+                    }
+                }
+            }
+        });
+
+        validation_result
     }
 
-    /// Add a headline.
+    /// Takes the focus from the focused widget of a property when enter is pressed, see `PropertyEditor::keyboard_commit`.
+    fn commit_on_enter(&self, ui: &Ui) {
+        if !self.settings.keyboard_commit || !ui.input(|i| i.key_pressed(Key::Enter)) {
+            return;
+        }
+        let Some(focused) = ui.memory(|m| m.focused()) else {
+            return;
+        };
+        // text edits handle enter themselves, single line ones by giving up the focus, multiline ones with a new line
+        if TextEdit::load_state(ui.ctx(), focused).is_some() {
+            return;
+        }
+        if self.settings.focus_order.borrow().contains(&focused) {
+            ui.memory_mut(|m| m.surrender_focus(focused));
+        }
+    }
+
+    /// Handles arrow key navigation between the widgets of the drawn properties, in the order they are drawn.
     ///
-    /// As with all content-adding functions, insertion order matters.
-    pub fn headline(mut self, text: impl Into<WidgetText>) -> Self {
-        self.entries.push(EditorLine::Headline(text.into()));
-        self
+    /// Only registered widgets are part of this, so labels, headlines and hidden or disabled properties are skipped.
+    fn navigate_focus(&self, ui: &Ui, store: &mut PropertyEditorStore) {
+        let focus_order = self.settings.focus_order.take();
+        if !self.arrow_key_navigation {
+            return;
+        }
+        let Some(from) = ui.memory(|m| m.focused()) else {
+            return;
+        };
+        let Some(pos) = focus_order.iter().position(|id| *id == from) else {
+            return;
+        };
+        let (next_key, previous_key) = match self.settings.orientation {
+            Orientation::Vertical => (Key::ArrowDown, Key::ArrowUp),
+            Orientation::Horizontal => (Key::ArrowRight, Key::ArrowLeft),
+        };
+        let direction = ui.input(|i| {
+            if !i.modifiers.is_none() {
+                0
+            } else if i.key_pressed(next_key) {
+                1
+            } else if i.key_pressed(previous_key) {
+                -1
+            } else {
+                0
+            }
+        });
+        if direction != 0
+            && let Some(to) = pos
+                .checked_add_signed(direction)
+                .and_then(|pos| focus_order.get(pos))
+        {
+            store.pending_focus = Some((from, *to));
+        }
     }
 
-    /// Add a property and assign it a name.
+    /// Sorts the properties into the order the user arranged them in, or the identity if they were not reordered.
     ///
-    /// As with all content-adding functions, insertion order matters.
+    /// Properties only move within their section, so a stored order that would move one past a headline or group border
+    /// is reset. So is one for a different number of properties.
+    fn apply_order(&mut self, store: &mut PropertyEditorStore) {
+        let mut section = 0;
+        self.property_sections = self
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                EditorLine::Property(_) => Some(section),
+                _ => {
+                    section += 1;
+                    None
+                }
+            })
+            .collect();
+        let count = self.property_sections.len();
+        if !self.reorderable {
+            self.order = (0..count).collect();
+            return;
+        }
+        let mut sorted = store.order.clone();
+        sorted.sort_unstable();
+        let valid = sorted.iter().copied().eq(0..count)
+            && store.order.iter().enumerate().all(|(position, index)| {
+                self.property_sections[position] == self.property_sections[*index]
+            });
+        if !valid {
+            store.order = (0..count).collect();
+        }
+        self.order = store.order.clone();
+
+        let mut properties: Vec<_> = std::mem::take(&mut self.entries)
+            .into_iter()
+            .map(|entry| match entry {
+                EditorLine::Property(p) => (Some(p), None),
+                entry => (None, Some(entry)),
+            })
+            .collect();
+        let mut property_slots = properties
+            .iter()
+            .enumerate()
+            .filter(|(_, (p, _))| p.is_some())
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let mut taken: Vec<_> = property_slots
+            .iter()
+            .map(|slot| properties[*slot].0.take())
+            .collect();
+        for (position, slot) in property_slots.drain(..).enumerate() {
+            properties[slot].0 = taken[self.order[position]].take();
+        }
+        self.entries = properties
+            .into_iter()
+            .filter_map(|entry| match entry {
+                (Some(p), _) => Some(EditorLine::Property(p)),
+                (None, entry) => entry,
+            })
+            .collect();
+    }
+
+    /// Moves a property dragged by its handle to where it was dropped, and shows where it would go while dragging.
     ///
-    /// This is the same as creating it first, then calling `Property::name` on it, and then adding it with `PropertyEditor::property`.
+    /// Properties can only be dropped within their own section, so headlines and groups stay where they are.
+    fn drop_dragged_property(&self, ui: &Ui, store: &mut PropertyEditorStore) {
+        let rows = self.row_rects.take();
+        let Some(payload) = DragAndDrop::payload::<ReorderPayload>(ui.ctx()) else {
+            return;
+        };
+        if payload.editor != self.id {
+            return;
+        }
+        let Some(pointer) = ui.ctx().pointer_interact_pos() else {
+            return;
+        };
+        let Some(&section) = self.property_sections.get(payload.index) else {
+            return;
+        };
+        let Some((target, rect)) = rows.into_iter().find(|(index, rect)| {
+            self.property_sections[*index] == section && rect.contains(pointer)
+        }) else {
+            return;
+        };
+        let vertical = self.settings.orientation == Orientation::Vertical;
+        let before = if vertical {
+            pointer.y < rect.center().y
+        } else {
+            pointer.x < rect.center().x
+        };
+        let stroke = ui.visuals().selection.stroke;
+        if vertical {
+            let y = if before { rect.top() } else { rect.bottom() };
+            ui.painter().hline(rect.x_range(), y, stroke);
+        } else {
+            let x = if before { rect.left() } else { rect.right() };
+            ui.painter().vline(x, rect.y_range(), stroke);
+        }
+        if !ui.input(|i| i.pointer.any_released()) {
+            return;
+        }
+        DragAndDrop::clear_payload(ui.ctx());
+        if target == payload.index {
+            return;
+        }
+        let order = &mut store.order;
+        let Some(from) = order.iter().position(|index| *index == payload.index) else {
+            return;
+        };
+        order.remove(from);
+        let Some(to) = order.iter().position(|index| *index == target) else {
+            return;
+        };
+        order.insert(if before { to } else { to + 1 }, payload.index);
+    }
+
+    /// Draws a single property, unless it is hidden by a collapsed section or the search text.
     ///
-    /// This takes a `Into<Property>`, so look at the `Property` docs to see what is possible.
-    pub fn named_property(
-        self,
-        name: impl Into<WidgetText>,
-        property: impl Into<Property<'a>>,
-    ) -> Self {
-        let property = property.into().name(name);
-        self.property(property)
+    /// Hidden properties are not drawn, so only their own validation result is taken into account.
+    fn draw_entry(
+        &self,
+        ui: &mut Ui,
+        p: Property<'a>,
+        section_collapsed: bool,
+        search: &str,
+    ) -> bool {
+        let search = search.trim().to_lowercase();
+        let matches_search = search.is_empty()
+            || p.name
+                .as_ref()
+                .is_some_and(|name| name.text().to_lowercase().contains(&search));
+        let position = self.property_index.get();
+        self.property_index.set(position + 1);
+        let index = self.order.get(position).copied().unwrap_or(position);
+        if section_collapsed || !matches_search {
+            return p.validation_result.is_ok();
+        }
+        let first_response = self.settings.responses.borrow().len();
+        let row_id = p.row_id(ui);
+        let top_left = ui.cursor().min;
+        let row_rect_id = ui.id().with("row rect").with(index);
+        if let Some(on_row) = &self.on_row {
+            // the rect of the last pass, registered before the widgets, so they stay on top for input
+            let rect = ui
+                .data(|d| d.get_temp::<Rect>(row_rect_id))
+                .unwrap_or(Rect::from_min_size(top_left, Vec2::ZERO));
+            let response = ui.interact(rect, row_rect_id, Sense::click());
+            (on_row.borrow_mut())(index, &response);
+        }
+        if self.reorderable && !self.settings.read_only {
+            self.settings.reorder_handle.set(Some(ReorderPayload {
+                editor: self.id,
+                index,
+            }));
+        }
+        let (valid, changed) = p.draw_tracked(ui, &self.settings, 0);
+        if changed {
+            self.changed_count.set(self.changed_count.get() + 1);
+        }
+        if valid && let Some(main) = self.settings.responses.borrow().get(first_response) {
+            self.validated_rows
+                .borrow_mut()
+                .insert(index, (row_id, main.response.clone()));
+        }
+        if self.settings.dirty.take() {
+            self.dirty_properties.borrow_mut().push(index);
+        }
+        self.settings.reorder_handle.set(None);
+        if self.on_row.is_some() {
+            let rect = self.settings.row_rect(ui, top_left);
+            ui.data_mut(|d| d.insert_temp(row_rect_id, rect));
+        }
+        if self.reorderable {
+            let rect = match self.settings.orientation {
+                // the grid ends the row, so the cursor is at the start of the next one
+                Orientation::Vertical => Rect::from_min_max(
+                    top_left,
+                    Pos2::new(ui.max_rect().right(), ui.cursor().min.y),
+                ),
+                Orientation::Horizontal => ui.min_rect(),
+            };
+            self.row_rects.borrow_mut().push((index, rect));
+        }
+        for PropertyResponse { response, .. } in &self.settings.responses.borrow()[first_response..]
+        {
+            if response.has_focus() {
+                self.focused_property.set(Some(index));
+            }
+            if response.hovered() {
+                self.hovered_property.set(Some(index));
+            }
+        }
+        valid
     }
 
-    /// Add a property.
+    /// Set to `true` to set all `Tracked` properties back to their original value in this pass, like "Cancel" in a dialog.
     ///
-    /// As with all content-adding functions, insertion order matters.
+    /// The reverted properties report a change, see `Property::on_change`.
+    pub fn revert_all(mut self, revert: bool) -> Self {
+        self.settings.revert_tracked = revert;
+        self
+    }
+
+    /// Set to `true` to take the current value of all `Tracked` properties as their new original in this pass, for example after saving.
+    pub fn accept_all(mut self, accept: bool) -> Self {
+        self.settings.accept_tracked = accept;
+        self
+    }
+
+    /// Call `on_row` for each shown property, with its index and the response of its whole row, for selectable rows.
     ///
-    /// This takes a `Into<Property>`, so look at the `Property` docs to see what is possible.
-    pub fn property(mut self, property: impl Into<Property<'a>>) -> Self {
-        let property = property.into();
-        self.show_descriptions = self.show_descriptions || property.description.is_some();
-        self.entries.push(EditorLine::Property(property));
+    /// The index counts the properties in the order they were added, without headlines and groups.
+    /// The row senses clicks, but its widgets stay on top, so only clicks next to them count.
+    /// `on_row` is called before the row is drawn, with the rect of the last pass, so anything painted with
+    /// the painter of the response's layer ends up behind the widgets.
+    /// ```
+    /// # use egui_property_editor::PropertyEditor;
+    /// # fn example(ui: &mut egui::Ui, a: &mut f32, b: &mut f32, selected: &mut Option<usize>) {
+    /// PropertyEditor::new("inspector")
+    ///     .on_row(|index, response| {
+    ///         if response.clicked() {
+    ///             *selected = Some(index);
+    ///         }
+    ///         if *selected == Some(index) {
+    ///             let color = response.ctx.style().visuals.selection.bg_fill;
+    ///             response.ctx.layer_painter(response.layer_id).rect_filled(response.rect, 2, color);
+    ///         }
+    ///     })
+    ///     .named_property("A", a)
+    ///     .named_property("B", b)
+    ///     .show(ui);
+    /// # }
+    /// ```
+    pub fn on_row(mut self, on_row: impl FnMut(usize, &Response) + 'a) -> Self {
+        self.on_row = Some(RefCell::new(Box::new(on_row)));
         self
     }
 
-    /// Adds a property for an `Option<T>`.
+    /// Configure the underlying `egui::Grid` beyond what the other settings offer.
     ///
-    /// This function does two things:
-    ///   * Create a checkbox, that sets the `Option<T>` to Some or None, using `default` to init.
-    ///   * If the property is `Some`, calls `property_cb` with `&mut T`, which then can return one or more `Property`s in a `PropertyList`.
+    /// The closure gets the grid after the editor applied its own settings, like stripes and column widths,
+    /// so anything set here wins. Only used for vertical editors.
     ///
-    /// To nest this, you can also create an optional property directly using `Property::new_optional`.
+    /// ```
+    /// # use egui_property_editor::PropertyEditor;
+    /// # fn f(ui: &mut egui::Ui, value: &mut u32) {
+    /// PropertyEditor::new("editor")
+    ///     .grid_config(|grid| grid.spacing([16.0, 8.0]).min_row_height(24.0))
+    ///     .named_property("Value", value)
+    ///     .show(ui);
+    /// # }
+    /// ```
+    pub fn grid_config(mut self, grid_config: impl FnOnce(Grid) -> Grid + 'a) -> Self {
+        self.grid_config = Some(Box::new(grid_config));
+        self
+    }
+
+    /// Set to `true` to show a drag handle in front of each property, so the user can reorder them.
     ///
-    /// For a detailed usage example, look at `examples/demo.rs`.
-    pub fn optional_property<T>(
-        self,
+    /// Properties can only be moved within their section, so headlines and groups act as fixed anchors.
+    /// The order is kept in memory, see the crate level docs on persistence, and is reset when the number of properties
+    /// changes. Use `PropertyEditor::show_with_order` to get it, for example to reorder your data to match.
+    /// Only properties drawing a name cell get a handle. Read-only editors show none.
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Validate across properties, such as "end must not be before start".
+    ///
+    /// `validate` returns the errors along with the index of the property to show each at, where the index counts the
+    /// properties in the order they were added, without headlines and groups.
+    /// It runs after all properties are drawn, so it sees the edits of this pass, and its errors make `show` return `false`.
+    /// They are shown as an outline over the widget of the flagged property, with the message on hover, like
+    /// `ErrorDisplay::Overlay`. A property that already failed its own validation keeps its own error.
+    ///
+    /// The closure is kept until then, so it cannot borrow values the properties borrow mutably. Share those
+    /// through a `RefCell` instead, which converts into a `Property` as well.
+    /// ```
+    /// # use egui_property_editor::{PropertyEditor, ValidationError};
+    /// # use std::cell::RefCell;
+    /// # fn example(ui: &mut egui::Ui, start: &RefCell<f32>, end: &RefCell<f32>) {
+    /// PropertyEditor::new("range")
+    ///     .named_property("Start", start)
+    ///     .named_property("End", end)
+    ///     .validate_all(|| {
+    ///         if *end.borrow() < *start.borrow() {
+    ///             vec![(1, ValidationError::CustomWithMessage("End is before start.".into()))]
+    ///         } else {
+    ///             vec![]
+    ///         }
+    ///     })
+    ///     .show(ui);
+    /// # }
+    /// ```
+    pub fn validate_all(
+        self,
+        validate: impl FnMut() -> Vec<(usize, ValidationError)> + 'a,
+    ) -> Self {
+        self.validators.borrow_mut().push(Box::new(validate));
+        self
+    }
+
+    /// Validate a group of properties together, such as "at least one of these must be checked".
+    ///
+    /// If `predicate` returns `false`, `error` is shown at the last property of the group, the one with the highest of `indices`.
+    /// The indices count the properties like the ones of `PropertyEditor::validate_all`, which this builds on,
+    /// so `predicate` is evaluated after the properties are drawn, and shares their values through `RefCell`s.
+    /// ```
+    /// # use egui_property_editor::{PropertyEditor, ValidationError};
+    /// # use std::cell::RefCell;
+    /// # fn example(ui: &mut egui::Ui, email: &RefCell<bool>, sms: &RefCell<bool>, push: &RefCell<bool>) {
+    /// PropertyEditor::new("notifications")
+    ///     .named_property("E-mail", email)
+    ///     .named_property("SMS", sms)
+    ///     .named_property("Push", push)
+    ///     .group_constraint(
+    ///         0..3,
+    ///         || *email.borrow() || *sms.borrow() || *push.borrow(),
+    ///         ValidationError::CustomWithMessage("Choose at least one.".into()),
+    ///     )
+    ///     .show(ui);
+    /// # }
+    /// ```
+    pub fn group_constraint(
+        self,
+        indices: impl IntoIterator<Item = usize>,
+        mut predicate: impl FnMut() -> bool + 'a,
+        error: ValidationError,
+    ) -> Self {
+        let last = indices.into_iter().max();
+        self.validate_all(move || match last {
+            Some(last) if !predicate() => vec![(last, error.clone())],
+            _ => vec![],
+        })
+    }
+
+    /// Collects the validation errors of all properties, without drawing anything, so no `Ui` is needed.
+    ///
+    /// Use this to check a form in tests, or before saving values that were changed by code.
+    /// This covers what is validated up front: `ValidatedProperty`, `TypedProperty` and `PropertyEditor::validate_all`.
+    /// Checks done by widgets while drawing, such as `NumericProperty::reject_out_of_range`, and nested properties are not covered,
+    /// since those only exist while drawing. Errors are in the order of the properties, with one error per property at most.
+    /// ```
+    /// # use egui_property_editor::{PropertyEditor, ValidatedProperty, ValidationError};
+    /// let mut port = 0u16;
+    /// let editor = PropertyEditor::new("server").named_property(
+    ///     "Port",
+    ///     ValidatedProperty::new(&mut port, |port| {
+    ///         if **port == 0 {
+    ///             Err(ValidationError::OutOfRange)
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     }),
+    /// );
+    /// assert_eq!(editor.validate_headless(), vec![ValidationError::OutOfRange]);
+    /// ```
+    pub fn validate_headless(&self) -> Vec<ValidationError> {
+        let mut cross_errors = HashMap::new();
+        for (index, error) in self.cross_errors() {
+            cross_errors.entry(index).or_insert(error);
+        }
+        self.entries
+            .iter()
+            .filter_map(|line| match line {
+                EditorLine::Property(property) => Some(property),
+                _ => None,
+            })
+            .enumerate()
+            .filter_map(|(index, property)| {
+                property
+                    .validation_result
+                    .clone()
+                    .err()
+                    .or_else(|| cross_errors.remove(&index))
+            })
+            .collect()
+    }
+
+    /// Checks if all properties added so far validate, without drawing or consuming the editor.
+    ///
+    /// Use this to decide on things drawn before the editor, such as disabling a "Save" button.
+    /// Like `show`, this is always `true` for read-only editors. Otherwise it is `true` if `PropertyEditor::validate_headless`
+    /// finds no errors, so properties that only validate while drawing, such as enums or nested properties, count as valid.
+    pub fn current_validation(&self) -> bool {
+        self.settings.read_only || self.validate_headless().is_empty()
+    }
+
+    /// Collects the values of the properties created with `Property::exported` into a JSON object, by their name.
+    ///
+    /// Use this with `PropertyEditor::apply_json` to save and load the values of an editor. If several properties share
+    /// a name, the last one wins. Nested properties are not part of it, and neither are values that fail to serialize.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Value {
+        self.exported_properties()
+            .filter_map(|(name, shim)| Some((name, (shim.export)()?)))
+            .collect::<serde_json::Map<_, _>>()
+            .into()
+    }
+
+    /// Overwrites the values of the properties created with `Property::exported` with the ones of the same name in `json`.
+    ///
+    /// Call this before `show`, so the loaded values are drawn right away. Keys without a property are ignored,
+    /// and properties without a key, or with a value that does not deserialize, are left as they are. If `json` is not an object,
+    /// nothing is applied. Returns the number of properties that were overwritten.
+    #[cfg(feature = "serde")]
+    pub fn apply_json(&self, json: &serde_json::Value) -> usize {
+        let Some(values) = json.as_object() else {
+            return 0;
+        };
+        self.exported_properties()
+            .filter(|(name, shim)| values.get(name).is_some_and(|value| (shim.import)(value)))
+            .count()
+    }
+
+    /// The exported properties, along with their name, see `Property::exported`.
+    #[cfg(feature = "serde")]
+    fn exported_properties(&self) -> impl Iterator<Item = (String, &ExportShim<'a>)> {
+        self.entries.iter().filter_map(|line| match line {
+            EditorLine::Property(property) => {
+                let name = property.name.as_ref()?.text().to_owned();
+                Some((name, property.export.as_ref()?))
+            }
+            _ => None,
+        })
+    }
+
+    /// Set to `true` if you want the inner grid to show stripes.
+    pub fn stripes(mut self, show_stripes: bool) -> Self {
+        self.show_stripes = show_stripes;
+        self
+    }
+
+    /// Set the color of the stripes, see `PropertyEditor::stripes`.
+    ///
+    /// Defaults to the faint background color of the egui style. Has no effect while stripes are off.
+    pub fn stripe_color(mut self, color: Color32) -> Self {
+        self.stripe_color = Some(color);
+        self
+    }
+
+    /// Set to `true` if you want to show a border around the whole property editor.
+    pub fn outer_border(mut self, outer_border: bool) -> Self {
+        self.group_all = outer_border;
+        self
+    }
+
+    /// Set the headline spacing, that is the distance of the headline to things.
+    ///
+    /// `y` is the gap above and below each headline, except above a headline in the first row. `x` indents the headline text.
+    /// Defaults to `(0.0, 5.0)`.
+    pub fn headline_spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.headline_spacing = spacing.into();
+        self
+    }
+
+    /// Set the spacing between widgets and rows, for a denser or looser editor.
+    ///
+    /// Defaults to the item spacing of the egui style. Only the editor itself is affected.
+    pub fn item_spacing(mut self, item_spacing: impl Into<Vec2>) -> Self {
+        self.item_spacing = Some(item_spacing.into());
+        self
+    }
+
+    /// Set to `false` to never discard a pass because the size of the editor changed.
+    ///
+    /// By default, the editor asks egui to discard a pass and draw again when its width changed, or on the first pass,
+    /// so it can be centered or right aligned without jumping around, and column groups line up right away.
+    /// Hosts that manage discards and repaints themselves can turn that off. The editor is then always placed
+    /// at the left of the available space, and changed widths take effect in the next frame.
+    pub fn auto_size(mut self, auto_size: bool) -> Self {
+        self.auto_size = auto_size;
+        self
+    }
+
+    /// Set the height above which the properties scroll, for long lists of properties.
+    ///
+    /// The search bar stays above the scroll area. The width of the editor includes the scroll bar,
+    /// so the sizing passes settle as usual. Headlines scroll along with the properties.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Set the minimum height of each row, for a uniform look. `None` uses the interact size of the egui style.
+    ///
+    /// Rows with taller contents still grow. Horizontal editors have no rows, so this does nothing for them.
+    pub fn row_height(mut self, row_height: Option<f32>) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Set to `true` to draw a separator line below each headline, spanning all columns.
+    ///
+    /// The line uses the width of the editor from the last pass, so it only shows up once the editor has been sized.
+    pub fn headline_rule(mut self, headline_rule: bool) -> Self {
+        self.headline_rule = headline_rule;
+        self
+    }
+
+    /// Set to `true` to make headlines clickable, collapsing or expanding the properties below them.
+    ///
+    /// The collapse state is remembered per headline text, see the crate level docs on persistence.
+    pub fn collapsible_headlines(mut self, collapsible_headlines: bool) -> Self {
+        self.collapsible_headlines = collapsible_headlines;
+        self
+    }
+
+    /// Set to `true` to show a search field above the properties, hiding all properties whose name does not contain the search text.
+    pub fn search_bar(mut self, show_search_bar: bool) -> Self {
+        self.show_search_bar = show_search_bar;
+        self
+    }
+
+    /// Set to `Orientation::Horizontal` to draw the properties inline, as "name: widget", wrapping as needed.
+    ///
+    /// This is meant for compact editors, such as toolbars. Descriptions are shown as tooltips, and headlines become separators.
+    /// Defaults to `Orientation::Vertical`, a grid with one property per row.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.settings.orientation = orientation;
+        self
+    }
+
+    /// Set the width of the description column, so long descriptions wrap at that width, no matter how much space there is.
+    ///
+    /// Without it, descriptions wrap at whatever width the grid gives them, which also takes a few passes to settle.
+    pub fn description_width(mut self, description_width: f32) -> Self {
+        self.settings.description_width = Some(description_width);
+        self
+    }
+
+    /// Render the editor read-only, for previews such as "view config" dialogs.
+    ///
+    /// All widget cells are disabled, so the same properties can be shown without being editable.
+    /// Names, descriptions and headlines are not dimmed, so they stay readable.
+    /// Validation is skipped, so `show` always returns `true`.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.settings.read_only = read_only;
+        self
+    }
+
+    /// Set how the name and description are aligned vertically against a taller widget, such as a multiline text edit.
+    ///
+    /// Defaults to `Align::Min`, the top of the row. `Align::Center` gives more balanced rows.
+    /// The alignment uses the heights of the last pass, so a changed height takes a pass to settle.
+    pub fn cell_valign(mut self, cell_valign: Align) -> Self {
+        self.settings.cell_valign = cell_valign;
+        self
+    }
+
+    /// Set how widgets are aligned horizontally in the widget column. `Align::Max` lines up their right edges.
+    ///
+    /// Defaults to `Align::Min`. Widgets are aligned against the widest one of the last pass, or the minimum column
+    /// width if that is wider, see `PropertyEditor::min_col_width` and `PropertyEditor::column_widths`.
+    /// Names stay left aligned. Horizontal editors ignore this.
+    pub fn align_widgets(mut self, align: Align) -> Self {
+        self.settings.widget_halign = align;
+        self
+    }
+
+    /// Set how widgets that failed validation are highlighted.
+    ///
+    /// By default, they get a 2 point outline and a "?", in the error color of the egui theme.
+    pub fn error_style(mut self, error_style: ErrorStyle) -> Self {
+        self.settings.error_style = error_style;
+        self
+    }
+
+    /// Set where validation errors are shown.
+    ///
+    /// Defaults to `ErrorDisplay::Overlay`. `ErrorDisplay::InlineMessage` does not cover the widget, which suits small widgets better.
+    pub fn error_display(mut self, error_display: ErrorDisplay) -> Self {
+        self.settings.error_display = error_display;
+        self
+    }
+
+    /// Set to `true` to commit edits with enter, and revert them with escape, like in a form.
+    ///
+    /// Enter takes the focus from the focused widget, so `Property::on_change` and `Property::debounce` report the edit.
+    /// Text edits keep handling enter themselves, so multiline ones still get new lines.
+    /// Escape sets a `Tracked` property back to the value it had when its widget got the focus. Other properties
+    /// do not know that value, so escape only takes the focus from them, as usual in egui.
+    pub fn keyboard_commit(mut self, keyboard_commit: bool) -> Self {
+        self.settings.keyboard_commit = keyboard_commit;
+        self
+    }
+
+    /// Set to `true` to only show the validation errors of a property once the user interacted with it.
+    ///
+    /// Forms that greet the user with errors on fields they did not fill in yet feel hostile. With this, a property counts
+    /// as touched once its widget had the focus, was clicked or changed, which is kept in egui memory.
+    /// Only showing the error waits: `show` and the other `show_*` functions still return `false` for invalid properties
+    /// that were not touched yet, so a "Save" button can be disabled right away.
+    pub fn validate_after_touch(mut self, validate_after_touch: bool) -> Self {
+        self.settings.validate_after_touch = validate_after_touch;
+        self
+    }
+
+    /// Give the dropdowns of enum properties with at least `min_variants` variants a search field.
+    ///
+    /// The field sits at the top of the dropdown and has the focus when it opens, so typing right away
+    /// narrows the variants down to the ones whose name contains the text, ignoring case.
+    /// This applies to `enum_property!`, `unit_enum_property!` and `EnumProperty`. Smaller enums keep the plain dropdown.
+    pub fn searchable_combos(mut self, min_variants: usize) -> Self {
+        self.settings.searchable_combos = Some(min_variants);
+        self
+    }
+
+    /// Set to `true` to let the up and down arrow keys move focus to the previous or next property.
+    ///
+    /// Widgets that use the arrow keys themselves, like `DragValue` or multiline text edits, keep them.
+    /// Tab and shift-tab always move through the properties top to bottom.
+    /// In a horizontal editor, the left and right arrow keys are used instead.
+    pub fn arrow_key_navigation(mut self, arrow_key_navigation: bool) -> Self {
+        self.arrow_key_navigation = arrow_key_navigation;
+        self
+    }
+
+    /// Share the width of the name and widget column with all other editors using the same `group` id.
+    ///
+    /// The widest member of the group wins, so stacked editors get visually aligned columns.
+    /// Widths are exchanged between passes, so a change takes an additional (discarded) pass to settle.
+    pub fn column_group(mut self, group: impl Into<Id>) -> Self {
+        self.column_group = Some(group.into());
+        self
+    }
+
+    /// Place `pairs` properties next to each other, for dense editors in wide windows, like a two-up form.
+    ///
+    /// The properties between two headlines are spread over `pairs` columns of name, widget and description,
+    /// left to right and then top to bottom. Each column is a grid of its own, so columns only line up within a column,
+    /// and a property with children or a tall widget pushes down the ones below it, but not its neighbors.
+    /// Keyboard navigation goes down one column before moving on to the next. Headlines, groups and spacers span all columns.
+    /// `grid_config` only applies to the grid around everything. Horizontal editors are not affected.
+    pub fn pairs_per_row(mut self, pairs: usize) -> Self {
+        self.pairs_per_row = pairs.max(1);
+        self
+    }
+
+    /// If you set this to some, will provide the inner grid with a minimal col width.
+    /// Will look more aligned, but will of course also consume a bit more space.
+    pub fn min_col_width(mut self, min_col_width: Option<f32>) -> Self {
+        self.min_column_width = min_col_width;
+        self
+    }
+
+    /// If you set this to some, the inner grid columns do not grow wider than this, for example with a long description.
+    ///
+    /// Minimum widths win over this, be it from `PropertyEditor::min_col_width` or `PropertyEditor::column_widths`.
+    /// Contents that are wider than the maximum wrap where they can, and overflow otherwise.
+    pub fn max_col_width(mut self, max_col_width: Option<f32>) -> Self {
+        self.max_column_width = max_col_width;
+        self
+    }
+
+    /// Set the minimum width of the name, widget and description column separately.
+    ///
+    /// Unlike `PropertyEditor::min_col_width`, this allows for example a narrow name column next to a wide widget column.
+    /// Both can be combined, in which case the larger minimum wins.
+    pub fn column_widths(
+        mut self,
+        name: Option<f32>,
+        widget: Option<f32>,
+        description: Option<f32>,
+    ) -> Self {
+        self.settings.column_widths = ColumnWidths {
+            name,
+            widget,
+            description,
+        };
+        self
+    }
+
+    /// Set how far nested properties, such as the ones of `optional_property` or `enum_property!`, are indented per level.
+    ///
+    /// The indent happens inside the name column. Defaults to the indent of the egui style, `0.0` disables it.
+    pub fn indent_width(mut self, indent_width: f32) -> Self {
+        self.settings.indent_width = Some(indent_width);
+        self
+    }
+
+    /// Set to `true` to show descriptions as a "?" tooltip next to the property name, instead of in a third column.
+    ///
+    /// Useful for compact editors, or editors with long descriptions.
+    pub fn description_as_tooltip(mut self, description_as_tooltip: bool) -> Self {
+        self.settings.description_as_tooltip = description_as_tooltip;
+        self
+    }
+
+    /// Set to true to always show description column
+    pub fn show_descriptions(mut self, show_descriptions: bool) -> Self {
+        self.settings.draw_description = show_descriptions;
+        self
+    }
+
+    /// Add a headline.
+    ///
+    /// As with all content-adding functions, insertion order matters.
+    pub fn headline(mut self, text: impl Into<WidgetText>) -> Self {
+        self.entries.push(EditorLine::Headline(text.into()));
+        self
+    }
+
+    /// Start a group, drawing a border around all entries until the matching `PropertyEditor::end_group`.
+    ///
+    /// The title is drawn like a headline, as the first row of the group. Groups can be nested.
+    /// Like `PropertyEditor::headline_rule`, the border uses the width of the editor from the last pass.
+    /// Horizontal editors draw the title, but no border.
+    pub fn begin_group(mut self, title: impl Into<WidgetText>) -> Self {
+        self.entries.push(EditorLine::GroupStart(title.into()));
+        self
+    }
+
+    /// Add an empty row of `height` points, for some room between properties that do not need a headline.
+    ///
+    /// As with all content-adding functions, insertion order matters. The row is hidden with a collapsed section,
+    /// and like headlines, properties are not reordered past it. Horizontal editors leave a gap of that width instead.
+    pub fn spacer(mut self, height: f32) -> Self {
+        self.entries.push(EditorLine::Spacer(height));
+        self
+    }
+
+    /// End the innermost group started with `PropertyEditor::begin_group`.
+    ///
+    /// Groups that are still open at the end of the editor are ended there.
+    pub fn end_group(mut self) -> Self {
+        self.entries.push(EditorLine::GroupEnd);
+        self
+    }
+
+    /// Add a property and assign it a name.
+    ///
+    /// As with all content-adding functions, insertion order matters.
+    ///
+    /// This is the same as creating it first, then calling `Property::name` on it, and then adding it with `PropertyEditor::property`.
+    ///
+    /// This takes a `Into<Property>`, so look at the `Property` docs to see what is possible.
+    pub fn named_property(
+        self,
+        name: impl Into<WidgetText>,
+        property: impl Into<Property<'a>>,
+    ) -> Self {
+        let property = property.into().name(name);
+        self.property(property)
+    }
+
+    /// Add a property.
+    ///
+    /// As with all content-adding functions, insertion order matters.
+    ///
+    /// This takes a `Into<Property>`, so look at the `Property` docs to see what is possible.
+    ///
+    /// Unless it has an `Property::id_salt`, the property gets one from the editor id and its insertion index,
+    /// so the state of its widgets does not depend on what was drawn before.
+    pub fn property(mut self, property: impl Into<Property<'a>>) -> Self {
+        let mut property = property.into();
+        if property.id_salt.is_none() {
+            let index = self
+                .entries
+                .iter()
+                .filter(|line| matches!(line, EditorLine::Property(_)))
+                .count();
+            property.id_salt = Some(self.id.with("property").with(index));
+        }
+        self.settings.draw_description =
+            self.settings.draw_description || property.description.is_some();
+        self.entries.push(EditorLine::Property(Box::new(property)));
+        self
+    }
+
+    /// Add all properties of an iterator, such as ones generated in a loop.
+    ///
+    /// This is the same as calling `PropertyEditor::property` for each of them, in order.
+    pub fn properties<P: Into<Property<'a>>>(
+        self,
+        properties: impl IntoIterator<Item = P>,
+    ) -> Self {
+        properties.into_iter().fold(self, Self::property)
+    }
+
+    /// Adds a property for an `Option<T>`.
+    ///
+    /// This function does two things:
+    ///   * Create a checkbox, that sets the `Option<T>` to Some or None, using `default` to init.
+    ///   * If the property is `Some`, calls `property_cb` with `&mut T`, which then can return one or more `Property`s in a `PropertyList`.
+    ///
+    /// To nest this, you can also create an optional property directly using `Property::new_optional`.
+    ///
+    /// For a detailed usage example, look at `examples/demo.rs`.
+    pub fn optional_property<T>(
+        self,
         name: impl Into<WidgetText>,
         value: &'a mut Option<T>,
         default: T,
@@ -317,34 +1764,1007 @@ impl<'a> PropertyEditor<'a> {
 enum EditorLine<'a> {
     /// A headline, i.e. a section introducing text
     Headline(WidgetText),
-    /// The actual property contents
-    Property(Property<'a>),
+    /// The actual property contents, boxed since they are a lot larger than the other entries
+    Property(Box<Property<'a>>),
+    /// The start of a bordered group, with its title
+    GroupStart(WidgetText),
+    /// The end of the innermost open group
+    GroupEnd,
+    /// An empty row of the given height
+    Spacer(f32),
 }
 
+/// Width changes up to this many points do not cause a sizing pass.
+const WIDTH_TOLERANCE: f32 = 0.5;
+
+/// The maximum number of sizing passes in a row, before the editor waits for its width to settle.
+const MAX_DISCARDS: u32 = 3;
+
 /// The persistent memory needed to draw this whole thing
+///
+/// Keyed by the persistent id of the editor. With the `serde` feature, this is persisted instead of temp storage.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 struct PropertyEditorStore {
     /// False on the first pass, used for discarding
     first_pass: bool,
     /// Used for ui allocation.
     last_width: f32,
+    /// The height of the last pass, see `PropertyEditor::measured_size`.
+    last_height: f32,
+    /// The number of passes in a row that requested a discard.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    discards: u32,
+    /// Collapse state of sections, keyed by the hash of the section. `true` means collapsed.
+    ///
+    /// This is never pruned, so it grows with every distinct section an editor has shown.
+    collapsed_sections: HashMap<u64, bool>,
+    /// The text currently entered into the search field.
+    search: String,
+    /// The column widths of the last pass, so changes can trigger a new sizing pass.
+    column_widths: ColumnWidths,
+    /// Focus change from arrow key navigation, applied in the next pass.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pending_focus: Option<(Id, Id)>,
+    /// The index of the property shown at each position, see `PropertyEditor::reorderable`.
+    order: Vec<usize>,
+    /// The widest widget of the last pass, see `PropertyEditor::align_widgets`.
+    widest_widget: f32,
 }
 
-impl PropertyEditorStore {
-    /// Loads from temp storage
-    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
-        ctx.data(|d| d.get_temp(id))
-    }
+/// What is dragged when reordering properties, see `PropertyEditor::reorderable`.
+#[derive(Debug, Clone, Copy)]
+struct ReorderPayload {
+    /// The id of the editor the property belongs to, so it cannot be dropped into another one.
+    editor: Id,
+    /// The index of the dragged property.
+    index: usize,
+}
 
-    /// Stores to temp storage
-    pub fn store(self, ctx: &Context, id: Id) {
-        ctx.data_mut(|d| d.insert_temp(id, self));
-    }
+/// The minimum widths of the three columns of an editor. `None` means no minimum.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnWidths {
+    /// Minimum width of the name column
+    pub name: Option<f32>,
+    /// Minimum width of the widget column
+    pub widget: Option<f32>,
+    /// Minimum width of the description column
+    pub description: Option<f32>,
 }
 
-/// The simpler of the two callback types for custom widget drawing.
-///
-/// Takes a &mut ui, returns a widget response.
+/// Everything a shown editor reports back, see `PropertyEditor::show_with_output`.
+#[derive(Debug, Clone)]
+pub struct ShowOutput {
+    /// If all properties validated, the same as the result of `PropertyEditor::show`.
+    pub valid: bool,
+    /// The widget responses of all properties drawn, see `PropertyEditor::show_with_responses`.
+    pub responses: Vec<PropertyResponse>,
+    /// The rect the editor took up, including the border.
+    pub rect: Rect,
+    /// The index of the hovered or focused property, see `PropertyEditor::show_with_active`.
+    pub active: Option<usize>,
+    /// The index of the property shown at each position, see `PropertyEditor::show_with_order`.
+    pub order: Vec<usize>,
+    /// The number of properties that changed, see `PropertyEditor::show_with_changes`.
+    pub changed: usize,
+    /// The indices of the properties that differ from their original, see `PropertyEditor::show_with_dirty`.
+    pub dirty: Vec<usize>,
+}
+
+/// How an editor lays out its properties, see `PropertyEditor::orientation`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Orientation {
+    /// A grid, with one property per row.
+    #[default]
+    Vertical,
+    /// Inline, left to right, wrapping as needed.
+    Horizontal,
+}
+
+/// Editor wide settings that each property needs to know about while drawing.
+///
+/// These are handed to every `PropertyDrawFn` as part of the `PropertyRow`, and should be passed on to nested properties.
+#[derive(Debug, Clone, Default)]
+pub struct EditorSettings {
+    /// If there is a third column for the description.
+    pub draw_description: bool,
+    /// If descriptions are shown as a tooltip next to the name, instead of in the third column.
+    pub description_as_tooltip: bool,
+    /// How properties are laid out.
+    pub orientation: Orientation,
+    /// The minimum widths of the columns.
+    pub column_widths: ColumnWidths,
+    /// How far nested properties are indented per level. `None` uses the indent of the egui style.
+    pub indent_width: Option<f32>,
+    /// The width descriptions are wrapped at, see `PropertyEditor::description_width`.
+    pub description_width: Option<f32>,
+    /// How the name and description cells are aligned vertically against a taller widget cell.
+    pub cell_valign: Align,
+    /// How widgets are aligned horizontally in the widget column, see `PropertyEditor::align_widgets`.
+    pub widget_halign: Align,
+    /// How failed validation is highlighted.
+    pub error_style: ErrorStyle,
+    /// Where validation errors are shown, see `PropertyEditor::error_display`.
+    pub error_display: ErrorDisplay,
+    /// If the widgets are disabled and validation is skipped, see `PropertyEditor::read_only`.
+    pub read_only: bool,
+    /// The focusable widgets of the properties drawn so far, see `PropertyRow::register_widget`.
+    focus_order: RefCell<Vec<Id>>,
+    /// If the natural width of the name and widget column is measured, for column groups.
+    measure_columns: bool,
+    /// The widest name and widget cell content drawn so far, if `measure_columns` is set.
+    measured_widths: Cell<[f32; 2]>,
+    /// The widget responses of the properties drawn so far, see `PropertyRow::register_widget`.
+    responses: RefCell<Vec<PropertyResponse>>,
+    /// The drag handle for the next name cell of a top level property, see `PropertyEditor::reorderable`.
+    reorder_handle: Cell<Option<ReorderPayload>>,
+    /// The width of a grid row in the last pass, for painting row backgrounds.
+    row_width: f32,
+    /// The width widgets are aligned in, see `PropertyEditor::align_widgets`.
+    widget_column_width: f32,
+    /// The widest widget cell content drawn so far, see `PropertyEditor::align_widgets`.
+    widest_widget: Cell<f32>,
+    /// If `Tracked` properties go back to their original value, see `PropertyEditor::revert_all`.
+    revert_tracked: bool,
+    /// If `Tracked` properties take their current value as the original, see `PropertyEditor::accept_all`.
+    accept_tracked: bool,
+    /// Set by `Tracked` properties that differ from their original, see `PropertyEditor::show_with_dirty`.
+    dirty: Cell<bool>,
+    /// If enter commits and escape reverts edits, see `PropertyEditor::keyboard_commit`.
+    keyboard_commit: bool,
+    /// If errors are only shown once a property was interacted with, see `PropertyEditor::validate_after_touch`.
+    validate_after_touch: bool,
+    /// The number of variants from which enum dropdowns get a search field, see `PropertyEditor::searchable_combos`.
+    searchable_combos: Option<usize>,
+}
+
+/// How widgets that failed validation are highlighted, see `PropertyEditor::error_style`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorStyle {
+    /// The width of the outline drawn around the widget.
+    pub stroke_width: f32,
+    /// Drawn on the right side of the widget. `None` draws no glyph.
+    pub glyph: Option<String>,
+    /// The color of the outline and glyph. `None` uses `error_fg_color` of the egui visuals, so it fits the theme.
+    pub color: Option<Color32>,
+}
+
+impl Default for ErrorStyle {
+    fn default() -> Self {
+        Self {
+            stroke_width: 2.0,
+            glyph: Some("?".to_owned()),
+            color: None,
+        }
+    }
+}
+
+/// Where validation errors are shown, see `PropertyEditor::error_display`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorDisplay {
+    /// An outline and glyph over the widget, see `ErrorStyle`. The message is shown when hovering the widget.
+    #[default]
+    Overlay,
+    /// The message as text, in the description column, or in a follow-up row if there is none.
+    /// Horizontal editors show it next to the widget. Only the color of `ErrorStyle` is used.
+    InlineMessage,
+}
+
+/// An icon in front of the name of a property, see `Property::icon`.
+#[derive(Debug, Clone)]
+pub enum PropertyIcon {
+    /// A single character, such as an emoji or a symbol. Needs no texture loading.
+    Glyph(char),
+    /// An image, such as one from `egui::include_image!`. Needs the image loaders of egui to be installed.
+    Image(ImageSource<'static>),
+}
+
+impl From<char> for PropertyIcon {
+    fn from(glyph: char) -> Self {
+        PropertyIcon::Glyph(glyph)
+    }
+}
+
+impl From<ImageSource<'static>> for PropertyIcon {
+    fn from(image: ImageSource<'static>) -> Self {
+        PropertyIcon::Image(image)
+    }
+}
+
+impl PropertyIcon {
+    /// Draws the icon, at the size of the body text.
+    fn ui(self, ui: &mut Ui) -> Response {
+        match self {
+            PropertyIcon::Glyph(glyph) => ui.label(glyph.to_string()),
+            PropertyIcon::Image(image) => {
+                let size = ui.text_style_height(&TextStyle::Body);
+                ui.add(Image::new(image).fit_to_exact_size(Vec2::splat(size)))
+            }
+        }
+    }
+}
+
+/// The response of a property widget, see `PropertyEditor::show_with_responses`.
+#[derive(Debug, Clone)]
+pub struct PropertyResponse {
+    /// The name of the property, or an empty string if it has none.
+    pub name: String,
+    /// The response of the widget.
+    pub response: Response,
+}
+
+/// Everything a `PropertyDrawFn` needs to draw the row of a single property.
+///
+/// The `*_cell` functions draw the individual grid cells the same way the built-in properties do.
+/// Each of them takes its part out of the row, so calling one twice draws an empty cell the second time.
+pub struct PropertyRow<'s> {
+    /// The name of the property
+    pub name: Option<WidgetText>,
+    /// The description of the property
+    pub description: Option<WidgetText>,
+    /// Shown when hovering the name
+    pub name_tooltip: Option<WidgetText>,
+    /// The result of the validation, see `ValidatedProperty`.
+    pub validation_result: Result<(), ValidationError>,
+    /// Text drawn in front of the widget, inside the widget cell.
+    pub prefix: Option<WidgetText>,
+    /// Text drawn after the widget, inside the widget cell.
+    pub suffix: Option<WidgetText>,
+    /// The font of everything in the widget cell, see `Property::font`.
+    pub font: Option<FontSelection>,
+    /// The width the widget takes at least, see `Property::min_widget_width`.
+    pub min_widget_width: Option<f32>,
+    /// Drawn in front of the name, see `Property::icon`.
+    pub icon: Option<PropertyIcon>,
+    /// Draws the name instead of a label, see `Property::name_widget`.
+    pub name_widget: Option<Box<NameWidgetFn<'s>>>,
+    /// Draws the contents of the help popup, see `Property::help`.
+    pub help: Option<Box<HelpFn<'s>>>,
+    /// Shows a failed validation instead of the error display of the editor, see `Property::on_error`.
+    pub on_error: Option<Box<ErrorFn<'s>>>,
+    /// The settings of the editor this row is drawn in. Pass these on when drawing nested properties.
+    pub settings: &'s EditorSettings,
+    /// How deeply nested this property is. Top level properties have a depth of 0.
+    pub depth: usize,
+    /// Set if the value of this row changed while drawing.
+    changed: &'s Cell<bool>,
+    /// The plain text of the name, kept for `PropertyResponse`, since `name_cell` takes the name.
+    name_text: String,
+    /// Where the cell heights of this row are kept in memory, see `PropertyEditor::cell_valign`.
+    row_id: Id,
+    /// The id of the name label, so screen readers can announce the widget by it.
+    name_id: Option<Id>,
+    /// If the row id is derived from an id salt, and the widgets should use it, see `Property::id_salt`.
+    salted: bool,
+    /// The number of children drawn so far, to give them ids, see `PropertyRow::draw_child`.
+    children: Cell<usize>,
+    /// The question of the reset button, see `Property::with_reset_confirm`.
+    reset_confirm: Option<WidgetText>,
+    /// If the description is markdown, see `Property::description_markdown`.
+    description_markdown: bool,
+    /// If the widget takes the place of the description cell, see `Property::no_description_cell`.
+    no_description_cell: bool,
+    /// Where the grid row of this property starts, if it is drawn into one, for widgets spanning the description column.
+    row_left: Option<f32>,
+}
+
+impl EditorSettings {
+    /// A copy of the configuration, for `PropertyEditor::reuse`.
+    ///
+    /// One-shot requests, such as `PropertyEditor::revert_all`, and what is collected while drawing are left out,
+    /// so a reused editor neither repeats them every frame nor starts with the state of an earlier pass.
+    fn reused(&self) -> Self {
+        Self {
+            draw_description: self.draw_description,
+            description_as_tooltip: self.description_as_tooltip,
+            orientation: self.orientation,
+            column_widths: self.column_widths,
+            indent_width: self.indent_width,
+            description_width: self.description_width,
+            cell_valign: self.cell_valign,
+            widget_halign: self.widget_halign,
+            error_style: self.error_style.clone(),
+            error_display: self.error_display,
+            read_only: self.read_only,
+            keyboard_commit: self.keyboard_commit,
+            validate_after_touch: self.validate_after_touch,
+            searchable_combos: self.searchable_combos,
+            ..Default::default()
+        }
+    }
+
+    /// The color of validation errors, see `ErrorStyle::color`.
+    fn error_color(&self, ui: &Ui) -> Color32 {
+        self.error_style
+            .color
+            .unwrap_or(ui.visuals().error_fg_color)
+    }
+
+    /// If the grid has a third column for descriptions.
+    pub fn has_description_column(&self) -> bool {
+        self.draw_description && !self.description_in_name_cell()
+    }
+
+    /// The rect of the row that started at `top_left`, after it was drawn, including nested properties.
+    fn row_rect(&self, ui: &Ui, top_left: Pos2) -> Rect {
+        let bottom_right = match self.orientation {
+            // the grid ends the row, so the cursor is at the start of the next one
+            Orientation::Vertical if self.row_width > 0.0 => {
+                Pos2::new(top_left.x + self.row_width, ui.cursor().min.y)
+            }
+            Orientation::Vertical => Pos2::new(ui.max_rect().right(), ui.cursor().min.y),
+            Orientation::Horizontal => Pos2::new(ui.cursor().min.x, ui.min_rect().bottom()),
+        };
+        Rect::from_min_max(top_left, bottom_right)
+    }
+
+    /// If descriptions are shown as a tooltip in the name cell. Horizontal editors have no description column.
+    fn description_in_name_cell(&self) -> bool {
+        self.description_as_tooltip || self.orientation == Orientation::Horizontal
+    }
+}
+
+impl PropertyRow<'_> {
+    /// Draws `property` as a child of this row, that is with the same settings, but one level deeper.
+    ///
+    /// Use this to draw nested properties after `ui.end_row()` in a custom draw function.
+    ///
+    /// If the child changed, this row counts as changed as well.
+    /// Unless it has an `Property::id_salt`, the child gets one from the id of this row and the number of children drawn before.
+    pub fn draw_child(&self, ui: &mut Ui, mut property: Property) -> bool {
+        let index = self.children.get();
+        self.children.set(index + 1);
+        if property.id_salt.is_none() {
+            property.id_salt = Some(self.row_id.with("child").with(index));
+        }
+        let (valid, changed) = property.draw_tracked(ui, self.settings, self.depth + 1);
+        if changed {
+            self.mark_changed();
+        }
+        valid
+    }
+
+    /// The id of this row, derived from its position or `Property::id_salt`. Use it for memory of custom draw functions,
+    /// such as `row.id().with("open")`.
+    ///
+    /// Ids from `property_widget_id` are derived from it as well. Widgets that pick their own id, such as a `TextEdit`
+    /// or `DragValue` without an explicit id, are not: egui gives them auto ids that depend on what was drawn before them.
+    pub fn id(&self) -> Id {
+        self.row_id
+    }
+
+    /// Registers `response` as the main widget of this row.
+    ///
+    /// The built-in properties call this for you. If you draw your own widget cell, you should call it as well, so keyboard
+    /// navigation knows about the widget, `Property::on_change` fires when the response changed,
+    /// and the response is part of `PropertyEditor::show_with_responses`.
+    /// Disabled and non-focusable widgets are not part of the keyboard navigation.
+    /// If `name_cell` was drawn before, the widget is labelled by the name, so screen readers announce it by it.
+    pub fn register_widget(&self, response: &Response) {
+        if let Some(name_id) = self.name_id {
+            response.clone().labelled_by(name_id);
+        }
+        if response.changed() {
+            self.mark_changed();
+        }
+        if response.enabled() && response.sense.is_focusable() {
+            self.settings.focus_order.borrow_mut().push(response.id);
+        }
+        self.settings.responses.borrow_mut().push(PropertyResponse {
+            name: self.name_text.clone(),
+            response: response.clone(),
+        });
+    }
+
+    /// Highlights `response` if the validation of this row failed, and shows the error when hovering it.
+    ///
+    /// Returns `true` if the validation succeeded.
+    ///
+    /// With `ErrorDisplay::InlineMessage`, the error is shown as text instead. If there is a description column,
+    /// `description_cell` already did that, otherwise it is shown here, in a follow-up row or next to the widget.
+    pub fn show_validation_result(&mut self, ui: &mut Ui, response: &Response) -> bool {
+        if self.settings.read_only {
+            self.validation_result = Ok(());
+            return true;
+        }
+        if response.has_focus() || response.clicked() || response.changed() {
+            ui.data_mut(|d| d.insert_temp(self.row_id.with("touched"), true));
+        }
+        if !self.touched(ui) {
+            return std::mem::replace(&mut self.validation_result, Ok(())).is_ok();
+        }
+        match std::mem::replace(&mut self.validation_result, Ok(())) {
+            Err(e) if self.on_error.is_some() => {
+                if let Some(on_error) = self.on_error.take() {
+                    on_error(ui, &e, response);
+                }
+                false
+            }
+            Err(e) if self.settings.error_display == ErrorDisplay::InlineMessage => {
+                if !self.settings.has_description_column() || self.spans_description() {
+                    let color = self.settings.error_color(ui);
+                    if self.settings.orientation == Orientation::Vertical {
+                        ui.label("");
+                        ui.colored_label(color, e.to_string());
+                        ui.end_row();
+                    } else {
+                        ui.colored_label(color, e.to_string());
+                    }
+                }
+                false
+            }
+            Err(e) => {
+                paint_error_overlay(ui, self.settings, response, &e);
+                false
+            }
+            Ok(_) => true,
+        }
+    }
+
+    /// Marks the value of this row as changed, for values that are changed without the response of the widget knowing.
+    pub fn mark_changed(&self) {
+        self.changed.set(true);
+    }
+
+    /// Draws the name cell, or an empty one if there is no name.
+    ///
+    /// Nested properties are indented here, so the columns stay aligned.
+    /// If the editor shows descriptions as tooltips, they are added here as a hoverable "?" next to the name.
+    pub fn name_cell(&mut self, ui: &mut Ui) -> Response {
+        let name = self.name.take();
+        let name_tooltip = self.name_tooltip.take();
+        let icon = self.icon.take();
+        let name_widget = self.name_widget.take();
+        let handle = if self.depth == 0 {
+            self.settings.reorder_handle.take()
+        } else {
+            None
+        };
+        let description = if self.settings.description_in_name_cell() {
+            self.description.take()
+        } else {
+            None
+        };
+        let inline = self.settings.orientation == Orientation::Horizontal;
+        let has_name = name.is_some();
+        let resp = self
+            .settings
+            .cell(ui, Some(0), self.settings.column_widths.name, |ui| {
+                self.aligned(ui, "name height", |ui| {
+                    let name_label = |ui: &mut Ui| {
+                        let label = |ui: &mut Ui, name: WidgetText| match name_widget {
+                            Some(name_widget) => name_widget(ui, name),
+                            None => ui.label(name),
+                        };
+                        let resp = match name {
+                            // Inline properties read as "name: widget".
+                            Some(name) if inline => {
+                                ui.horizontal(|ui| {
+                                    ui.spacing_mut().item_spacing.x = 0.0;
+                                    let resp = label(ui, name);
+                                    ui.label(":");
+                                    resp
+                                })
+                                .inner
+                            }
+                            name => label(ui, name.unwrap_or_else(|| "".into())),
+                        };
+                        match name_tooltip {
+                            Some(name_tooltip) => resp.on_hover_text(name_tooltip),
+                            None => resp,
+                        }
+                    };
+                    let indent = self.settings.indent_width.unwrap_or(ui.spacing().indent)
+                        * self.depth as f32;
+                    if indent <= 0.0 && description.is_none() && icon.is_none() && handle.is_none()
+                    {
+                        return name_label(ui);
+                    }
+                    ui.horizontal(|ui| {
+                        if let Some(handle) = handle {
+                            let id = handle.editor.with(("reorder handle", handle.index));
+                            ui.dnd_drag_source(id, handle, |ui| ui.weak("☰"));
+                        }
+                        if indent > 0.0 {
+                            ui.add_space(indent);
+                        }
+                        if let Some(icon) = icon {
+                            icon.ui(ui);
+                        }
+                        let resp = name_label(ui);
+                        if let Some(description) = description {
+                            let markdown = self.description_markdown;
+                            ui.weak("?").on_hover_ui(|ui| {
+                                if markdown {
+                                    markdown_label(ui, description.text());
+                                } else {
+                                    ui.label(description);
+                                }
+                            });
+                        }
+                        resp
+                    })
+                    .inner
+                })
+            });
+        if has_name {
+            self.name_id = Some(resp.id);
+        }
+        resp
+    }
+
+    /// Draws the widget cell with `add_contents`, and returns whatever that returns.
+    ///
+    /// If there is a prefix or suffix, they are placed left and right of the widget.
+    /// If there is a font, it is used for everything in the cell.
+    /// If there is a minimum widget width, `add_contents` gets at least that much room.
+    pub fn widget_cell<R>(&mut self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        let prefix = self.prefix.take();
+        let suffix = self.suffix.take();
+        let help = self.help.take();
+        // a read-only value cannot be reset
+        let reset_confirm = self
+            .reset_confirm
+            .take()
+            .filter(|_| !self.settings.read_only);
+        let reset_confirmed_id = self.row_id.with("reset confirmed");
+        let font = self.font.take();
+        let read_only = self.settings.read_only;
+        let salt = self.salted.then_some(self.row_id);
+        let min_widget_width = self.min_widget_width;
+        let add_contents = |ui: &mut Ui| {
+            let Some(min_width) = min_widget_width else {
+                return add_contents(ui);
+            };
+            // scoped, so the sizes do not leak into the prefix, suffix or following cells
+            ui.scope(|ui| {
+                ui.set_min_width(min_width);
+                let spacing = ui.spacing_mut();
+                spacing.interact_size.x = spacing.interact_size.x.max(min_width);
+                spacing.slider_width = spacing.slider_width.max(min_width);
+                spacing.combo_width = spacing.combo_width.max(min_width);
+                spacing.text_edit_width = spacing.text_edit_width.max(min_width);
+                add_contents(ui)
+            })
+            .inner
+        };
+        let add_contents = |ui: &mut Ui| {
+            if !read_only {
+                return add_contents(ui);
+            }
+            ui.add_enabled_ui(false, add_contents).inner
+        };
+        let add_contents = |ui: &mut Ui| {
+            let Some(salt) = salt else {
+                return add_contents(ui);
+            };
+            let info = UiStackInfo::default().with_tag_value(ID_SALT_TAG, salt);
+            ui.scope_builder(
+                UiBuilder::new().id_salt(salt).ui_stack_info(info),
+                add_contents,
+            )
+            .inner
+        };
+        let contents = |ui: &mut Ui| {
+            if prefix.is_none() && suffix.is_none() && help.is_none() && reset_confirm.is_none() {
+                return add_contents(ui);
+            }
+            ui.horizontal(|ui| {
+                if let Some(prefix) = prefix {
+                    ui.label(prefix);
+                }
+                let inner = add_contents(ui);
+                if let Some(suffix) = suffix {
+                    ui.label(suffix);
+                }
+                if let Some(message) = reset_confirm {
+                    let button = ui.small_button("⟲").on_hover_text("Reset");
+                    Popup::from_toggle_button_response(&button)
+                        .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+                        .show(|ui| {
+                            ui.label(message);
+                            ui.horizontal(|ui| {
+                                if ui.button("Yes").clicked() {
+                                    // applied by the property when it is drawn next
+                                    ui.data_mut(|d| d.insert_temp(reset_confirmed_id, true));
+                                    ui.ctx().request_repaint();
+                                    ui.close();
+                                }
+                                if ui.button("No").clicked() {
+                                    ui.close();
+                                }
+                            });
+                        });
+                }
+                if let Some(help) = help {
+                    let button = ui.small_button("?");
+                    Popup::from_toggle_button_response(&button)
+                        .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+                        .show(|ui| {
+                            ui.set_max_width(300.0);
+                            help(ui);
+                        });
+                }
+                inner
+            })
+            .inner
+        };
+        let contents = |ui: &mut Ui| {
+            if !self.aligns_widgets() {
+                return contents(ui);
+            }
+            let factor = if self.settings.widget_halign == Align::Center {
+                0.5
+            } else {
+                1.0
+            };
+            let width_id = self.row_id.with("widget width");
+            let width = ui.data(|d| d.get_temp::<f32>(width_id));
+            ui.horizontal(|ui| {
+                let column_width = self.settings.widget_column_width;
+                if let Some(width) = width
+                    && column_width > width
+                {
+                    ui.add_space((column_width - width) * factor);
+                }
+                let inner = ui.scope(contents);
+                let width = inner.response.rect.width();
+                ui.data_mut(|d| d.insert_temp(width_id, width));
+                let widest = &self.settings.widest_widget;
+                widest.set(widest.get().max(width));
+                inner.inner
+            })
+            .inner
+        };
+        let contents = |ui: &mut Ui| {
+            if !self.aligns_cells() {
+                return contents(ui);
+            }
+            // measured, so the other cells can align against it
+            let inner = ui.scope(contents);
+            let widget_height_id = self.row_id.with("widget height");
+            ui.data_mut(|d| d.insert_temp(widget_height_id, inner.response.rect.height()));
+            inner.inner
+        };
+        // the right edge of the description column, if the widget takes its place
+        let spans_to = self
+            .row_left
+            .filter(|_| self.spans_description())
+            .map(|row_left| {
+                if self.settings.row_width > 0.0 {
+                    row_left + self.settings.row_width
+                } else {
+                    ui.max_rect().right()
+                }
+            });
+        // a spanning widget would make the widget column as wide as both
+        let measured_column = if spans_to.is_some() { None } else { Some(1) };
+        let draw = |ui: &mut Ui| {
+            self.settings.cell(
+                ui,
+                measured_column,
+                self.settings.column_widths.widget,
+                |ui| {
+                    let Some(font) = font else {
+                        return contents(ui);
+                    };
+                    // scoped, so the font does not leak into the following cells
+                    ui.scope(|ui| {
+                        let font_id = font.resolve(ui.style());
+                        let style = ui.style_mut();
+                        // the text edit of a DragValue uses its text style, and ignores the override
+                        let drag_value_text_style = style.drag_value_text_style.clone();
+                        style
+                            .text_styles
+                            .insert(drag_value_text_style, font_id.clone());
+                        style.override_font_id = Some(font_id);
+                        contents(ui)
+                    })
+                    .inner
+                },
+            )
+        };
+        let Some(right) = spans_to else {
+            return draw(ui);
+        };
+        // drawn outside of the grid layout, like headlines, so only the height counts for the grid
+        let rect = Rect::from_min_max(ui.cursor().min, Pos2::new(right, ui.max_rect().bottom()));
+        let mut span_ui = ui.new_child(
+            UiBuilder::new()
+                .id_salt(self.row_id.with("spanning widget"))
+                .max_rect(rect)
+                .layout(*ui.layout()),
+        );
+        let inner = draw(&mut span_ui);
+        ui.allocate_space(Vec2::Y * span_ui.min_rect().height());
+        inner
+    }
+
+    /// The search field for the dropdown of an enum property with `variant_count` variants.
+    ///
+    /// Call `ComboSearch::show` first thing in `ComboBox::show_ui`, and skip the variants `ComboSearch::matches`
+    /// rejects. The field is only drawn if the editor enables it for this many variants, see `PropertyEditor::searchable_combos`.
+    pub fn combo_search(&self, variant_count: usize) -> ComboSearch {
+        let enabled = self
+            .settings
+            .searchable_combos
+            .is_some_and(|min_variants| variant_count >= min_variants);
+        ComboSearch {
+            id: enabled.then(|| self.row_id.with("combo search")),
+            filter: String::new(),
+        }
+    }
+
+    /// If errors of this row are shown, see `PropertyEditor::validate_after_touch`.
+    fn touched(&self, ui: &Ui) -> bool {
+        !self.settings.validate_after_touch
+            || ui.data(|d| {
+                d.get_temp::<bool>(self.row_id.with("touched"))
+                    .unwrap_or(false)
+            })
+    }
+
+    /// If the widget takes the place of the description cell, see `Property::no_description_cell`.
+    fn spans_description(&self) -> bool {
+        self.no_description_cell
+            && self.settings.has_description_column()
+            && self.settings.orientation == Orientation::Vertical
+    }
+
+    /// Draws the description cell, or an empty one if there is no description.
+    ///
+    /// Does nothing if there is no description column.
+    pub fn description_cell(&mut self, ui: &mut Ui) {
+        if !self.settings.has_description_column() || self.spans_description() {
+            return;
+        }
+        let description = self.description.take();
+        let error = match &self.validation_result {
+            Err(e)
+                if self.settings.error_display == ErrorDisplay::InlineMessage
+                    && !self.settings.read_only
+                    && self.touched(ui) =>
+            {
+                Some(e.to_string())
+            }
+            _ => None,
+        };
+        let description_width = self.settings.description_width;
+        let min_width = match (self.settings.column_widths.description, description_width) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        self.settings.cell(ui, None, min_width, |ui| {
+            if description.is_none() && error.is_none() {
+                ui.label("");
+                return;
+            }
+            self.aligned(ui, "description height", |ui| {
+                ui.with_layout(Layout::top_down(Align::Min), |ui| {
+                    if let Some(description_width) = description_width {
+                        ui.set_max_width(description_width);
+                    }
+                    ui.style_mut().wrap_mode = Some(TextWrapMode::Wrap);
+                    match description {
+                        Some(description) if self.description_markdown => {
+                            markdown_label(ui, description.text());
+                        }
+                        Some(description) => {
+                            ui.label(description);
+                        }
+                        None => {}
+                    }
+                    if let Some(error) = error {
+                        ui.colored_label(self.settings.error_color(ui), error);
+                    }
+                });
+            });
+        });
+    }
+
+    /// If the name and description cells are aligned against the widget cell, see `PropertyEditor::cell_valign`.
+    fn aligns_cells(&self) -> bool {
+        self.settings.cell_valign != Align::Min
+            && self.settings.orientation == Orientation::Vertical
+    }
+
+    /// If widgets are aligned horizontally in their column, see `PropertyEditor::align_widgets`.
+    fn aligns_widgets(&self) -> bool {
+        self.settings.widget_halign != Align::Min
+            && self.settings.orientation == Orientation::Vertical
+    }
+
+    /// Draws the contents of the name or description cell, aligned vertically against the widget cell.
+    ///
+    /// The widget cell is drawn later, so this uses its height and the height of `add_contents` from the last pass.
+    /// `cell` tells the cells of the row apart in memory.
+    fn aligned<R>(&self, ui: &mut Ui, cell: &str, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        if !self.aligns_cells() {
+            return add_contents(ui);
+        }
+        let factor = if self.settings.cell_valign == Align::Center {
+            0.5
+        } else {
+            1.0
+        };
+        let widget_height_id = self.row_id.with("widget height");
+        let height_id = self.row_id.with(cell);
+        let (widget_height, height) = ui.data(|d| {
+            (
+                d.get_temp::<f32>(widget_height_id),
+                d.get_temp::<f32>(height_id),
+            )
+        });
+        ui.vertical(|ui| {
+            if let (Some(widget_height), Some(height)) = (widget_height, height)
+                && widget_height > height
+            {
+                ui.add_space((widget_height - height) * factor);
+            }
+            let inner = ui.scope(add_contents);
+            ui.data_mut(|d| d.insert_temp(height_id, inner.response.rect.height()));
+            inner.inner
+        })
+        .inner
+    }
+}
+
+impl EditorSettings {
+    /// Draws a grid cell, making sure it is at least `min_width` wide.
+    ///
+    /// If `measured_column` is set and columns are measured, the natural width of the content is recorded.
+    fn cell<R>(
+        &self,
+        ui: &mut Ui,
+        measured_column: Option<usize>,
+        min_width: Option<f32>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        let measured_column = measured_column.filter(|_| self.measure_columns);
+        if min_width.is_none() && measured_column.is_none() {
+            return add_contents(ui);
+        }
+        ui.scope(|ui| {
+            if let Some(min_width) = min_width {
+                ui.set_min_width(min_width);
+            }
+            let Some(column) = measured_column else {
+                return add_contents(ui);
+            };
+            // measure in an inner scope, so the minimum width does not count
+            let inner = ui.scope(add_contents);
+            let mut widths = self.measured_widths.get();
+            widths[column] = widths[column].max(inner.response.rect.width());
+            self.measured_widths.set(widths);
+            inner.inner
+        })
+        .inner
+    }
+
+    /// Draws `property` inline into the current cell, without its own name or description cell.
+    ///
+    /// For properties that combine several widgets in a single row. The widgets still take part in keyboard navigation,
+    /// and their responses are named `name`. Returns if the property validated, and if it changed.
+    fn draw_inline(&self, ui: &mut Ui, name: &str, property: Property) -> (bool, bool) {
+        let inline_settings = EditorSettings {
+            orientation: Orientation::Horizontal,
+            error_style: self.error_style.clone(),
+            error_display: self.error_display,
+            read_only: self.read_only,
+            validate_after_touch: self.validate_after_touch,
+            searchable_combos: self.searchable_combos,
+            ..Default::default()
+        };
+        let result = ui
+            .horizontal(|ui| property.draw_tracked(ui, &inline_settings, 0))
+            .inner;
+        self.focus_order
+            .borrow_mut()
+            .extend(inline_settings.focus_order.take());
+        self.responses
+            .borrow_mut()
+            .extend(
+                inline_settings
+                    .responses
+                    .take()
+                    .into_iter()
+                    .map(|response| PropertyResponse {
+                        name: name.to_owned(),
+                        ..response
+                    }),
+            );
+        result
+    }
+}
+
+/// The shared memory of all editors in a column group, see `PropertyEditor::column_group`.
+#[derive(Debug, Clone, Default)]
+struct ColumnGroupStore {
+    /// The pass `current` is collected in.
+    pass: u64,
+    /// The widest name and widget column of the last pass. This is what the members use.
+    previous: [f32; 2],
+    /// The widest name and widget column of the current pass, so far.
+    current: [f32; 2],
+}
+
+impl ColumnGroupStore {
+    /// Loads from temp storage
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data(|d| d.get_temp(id))
+    }
+
+    /// Stores to temp storage
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+
+    /// The first member to draw in a new pass moves the collected widths over.
+    fn begin_pass(&mut self, pass: u64) {
+        if self.pass != pass {
+            self.pass = pass;
+            self.previous = self.current;
+            self.current = [0.0; 2];
+        }
+    }
+
+    /// Raises the minimum column widths of `settings` to the ones of the group, and enables measuring.
+    fn apply(&self, settings: &mut EditorSettings) {
+        let widen = |width: Option<f32>, group_width: f32| {
+            if group_width > 0.0 {
+                Some(width.map_or(group_width, |width| width.max(group_width)))
+            } else {
+                width
+            }
+        };
+        settings.column_widths.name = widen(settings.column_widths.name, self.previous[0]);
+        settings.column_widths.widget = widen(settings.column_widths.widget, self.previous[1]);
+        settings.measure_columns = true;
+    }
+
+    /// Merges the measured widths of a member. Returns `true` if they exceed what the member was drawn with.
+    fn merge(&mut self, settings: &EditorSettings) -> bool {
+        let measured = settings.measured_widths.get();
+        let mut exceeded = false;
+        for (column, width) in measured.into_iter().enumerate() {
+            self.current[column] = self.current[column].max(width);
+            exceeded |= width > self.previous[column];
+        }
+        exceeded
+    }
+}
+
+impl PropertyEditorStore {
+    /// Loads from temp storage
+    #[cfg(not(feature = "serde"))]
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data(|d| d.get_temp(id))
+    }
+
+    /// Stores to temp storage
+    #[cfg(not(feature = "serde"))]
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+
+    /// Loads from persisted storage
+    #[cfg(feature = "serde")]
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    /// Stores to persisted storage
+    #[cfg(feature = "serde")]
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// The simpler of the two callback types for custom widget drawing.
+///
+/// Takes a &mut ui, returns a widget response.
 pub type PropertyWidgetFn<'a> = dyn FnOnce(&mut Ui) -> Response + 'a;
 
 /// The more complex, somewhat internal, drawing function.
@@ -352,14 +2772,19 @@ pub type PropertyWidgetFn<'a> = dyn FnOnce(&mut Ui) -> Response + 'a;
 /// Do not use if you can avoid it.
 ///
 /// Takes all Property data and draws the thing. Do not forget to call `ui.next_row()` :)
-pub type PropertyDrawFn<'a> = dyn FnOnce(
-        &mut Ui,
-        Option<WidgetText>,
-        Option<WidgetText>,
-        Result<(), ValidationError>,
-        bool,
-    ) -> bool
-    + 'a;
+pub type PropertyDrawFn<'a> = dyn FnOnce(&mut Ui, PropertyRow<'_>) -> bool + 'a;
+
+/// Draws the contents of the help popup of a property, see `Property::help`.
+pub type HelpFn<'a> = dyn FnOnce(&mut Ui) + 'a;
+
+/// Shows a failed validation of a property, see `Property::on_error`.
+pub type ErrorFn<'a> = dyn FnOnce(&mut Ui, &ValidationError, &Response) + 'a;
+
+/// Sums up a value in a line of text, see `EnumProperty::summary_fn` and `Property::new_optional_summarized`.
+pub type SummaryFn<'a, T> = dyn Fn(&T) -> WidgetText + 'a;
+
+/// Draws the name of a property in its name cell, see `Property::name_widget`.
+pub type NameWidgetFn<'a> = dyn FnOnce(&mut Ui, WidgetText) -> Response + 'a;
 
 /// An editable property.
 ///
@@ -370,7 +2795,19 @@ pub type PropertyDrawFn<'a> = dyn FnOnce(
 /// There is an implementation of `From<T> for Property` for more or less all basic types, and `String`.
 ///   * `&mut u8,i8,..,f32,f64` integer and floating point types will become a `DragValue`.
 ///   * `&mut String` will become a single line edit.
-///   * `&mut bool` will become a checkbox.
+///   * `&mut bool` will become a checkbox, see `BoolProperty` for a switch instead.
+///   * `&mut Option<T>` of the types above, `String` and `Duration` will become a checkbox, followed by the widget of `T` while it is ticked.
+///   * `&mut Option<bool>` will become a tri-state checkbox that cycles through `None`, `Some(true)` and `Some(false)`.
+///     This changed: it used to be a checkbox followed by a second one for the value, like the other options.
+///     `Property::new_optional` still gives a checkbox for `Some`, with the value in a row below.
+///   * `&mut NonZeroU8,..,NonZeroIsize` will become a `DragValue` that refuses zero, see `NonZeroProperty`.
+///   * `&mut RangeInclusive<T>` and `&mut Range<T>` of numeric types will become two `DragValue`s, see `RangeProperty`.
+///   * `&AtomicBool,&AtomicU8,..,&AtomicIsize` will become a checkbox or `DragValue` that stores changes back, see `AtomicProperty`.
+///   * `&RefCell<T>` will become the widget of `&mut T`, for values shared with `PropertyEditor::validate_all`.
+///   * `&mut SystemTime` will become `DragValue`s for the date and time in UTC.
+///   * `&mut chrono::NaiveDate`, `NaiveTime` and `NaiveDateTime` will become `DragValue`s for the date and time, with the `chrono` feature.
+///
+/// Apart from ranges, all of these get a right-click context menu to copy and paste their value, see `PropertyClipboard`.
 ///
 /// Additionally, two element tuples `(N, T)` where `N` is a `Into<WidgetText>` and `T` is a `Into<Property>` are equivalent to
 /// ```ignore
@@ -406,28 +2843,23 @@ pub type PropertyDrawFn<'a> = dyn FnOnce(
 ///
 /// It uses `PropertyDrawFn` - which has the following signature:
 ///```ignore
-///pub type PropertyDrawFn<'a> = dyn FnOnce(
-///         &mut Ui,
-///         Option<WidgetText>,
-///         Option<WidgetText>,
-///         Result<(), ValidationError>,
-///         bool,
-///     ) -> bool
-///     + 'a
+///pub type PropertyDrawFn<'a> = dyn FnOnce(&mut Ui, PropertyRow<'_>) -> bool + 'a;
 ///```
 ///
-/// The first parameters is the target ui, followed by an optional name, description, the result of the validation (see `Validation` above), and bool indicating if there is a thrid column for the description in the first place.
+/// The first parameters is the target ui, followed by a `PropertyRow`. It holds the optional name, description, the result of the validation (see `Validation` above),
+/// and the `EditorSettings` of the editor this is drawn in.
 /// The return value is "did validation succeed or not".
 ///
 /// You need to
-///   * Check if the name exists, and otherwise draw it empty.
-///   * Draw your widget.
-///   * Check if there is a description column. If yes, check if the description is there, or draw it empty. If not, there is no thrid column.
+///   * Check if the name exists, and otherwise draw it empty. `PropertyRow::name_cell` does that for you.
+///   * Draw your widget, preferably inside `PropertyRow::widget_cell`, which also takes care of prefix and suffix.
+///   * Check if there is a description column. If yes, check if the description is there, or draw it empty. If not, there is no thrid column. `PropertyRow::description_cell` does that for you.
 ///   * `ui.end_row()` **is your responsibility when providing a custom draw function.**
-///   * In the end, you must return true of false, indicating if the validation result is ok or not.
+///   * In the end, you must return true of false, indicating if the validation result is ok or not. `PropertyRow::show_validation_result` highlights your widget and tells you.
 ///
 /// The end-row-point is why this function is a bit of a (visual) footgun. However, it allows you too add additional lines after this.
 /// The enum macros and `Property::new_optional` use this to add content after initial combo boxes or checkboxes.
+/// Such nested properties should be drawn with `PropertyRow::draw_child`, so they are indented below their parent.
 ///
 /// For detailed use, i recommend reading the source.
 pub struct Property<'a> {
@@ -435,34 +2867,575 @@ pub struct Property<'a> {
     name: Option<WidgetText>,
     /// The description of this property
     description: Option<WidgetText>,
+    /// Shown when hovering the name
+    name_tooltip: Option<WidgetText>,
     /// The dynamic drawing function that will eventually be consumed to draw this property
-    draw_fn: Box<PropertyDrawFn<'a>>,
+    draw_fn: PropertyDraw<'a>,
     /// The result of a validation operation. Will usually be `Ok(())`, except if the `Property` is created out of a `ValidatedProperty`.
     validation_result: Result<(), ValidationError>,
+    /// Text in front of the widget
+    prefix: Option<WidgetText>,
+    /// Text after the widget
+    suffix: Option<WidgetText>,
+    /// The font of the widget cell
+    font: Option<FontSelection>,
+    /// The width the widget takes at least
+    min_widget_width: Option<f32>,
+    /// The icon in front of the name
+    icon: Option<PropertyIcon>,
+    /// Draws the name instead of a label
+    name_widget: Option<Box<NameWidgetFn<'a>>>,
+    /// Draws the contents of the help popup
+    help: Option<Box<HelpFn<'a>>>,
+    /// Shows a failed validation instead of the default error display
+    on_error: Option<Box<ErrorFn<'a>>>,
+    /// Called when the value changed while drawing.
+    on_change: Option<Box<dyn FnOnce() + 'a>>,
+    /// Quiet period before a change is reported, see `Property::debounce`.
+    debounce: Option<Duration>,
+    /// If the row gets a background tint
+    highlight: bool,
+    /// The color of the background tint, instead of the one derived from the selection color
+    highlight_color: Option<Color32>,
+    /// Seeds the ids of the widgets, see `Property::id_salt`.
+    id_salt: Option<Id>,
+    /// If the description is markdown, see `Property::description_markdown`.
+    description_markdown: bool,
+    /// If the widget takes the place of the description cell, see `Property::no_description_cell`.
+    no_description_cell: bool,
+    /// Serializes and deserializes the value, see `Property::exported`.
+    #[cfg(feature = "serde")]
+    export: Option<ExportShim<'a>>,
+}
+
+/// Serializes and deserializes the value of a property, for `PropertyEditor::to_json` and `PropertyEditor::apply_json`.
+#[cfg(feature = "serde")]
+struct ExportShim<'a> {
+    /// Serializes the current value, `None` if it fails
+    export: Box<dyn Fn() -> Option<serde_json::Value> + 'a>,
+    /// Deserializes a value and overwrites the current one with it, returning if it did
+    import: Box<dyn Fn(&serde_json::Value) -> bool + 'a>,
 }
 
 impl<'a> Property<'a> {
     /// Create a new property from a callback that adds a widget to Ui, and returns the response of it.
     pub fn from_widget_fn(cb: impl FnOnce(&mut Ui) -> Response + 'a) -> Self {
-        Self {
-            name: None,
-            description: None,
-            draw_fn: Box::new(|ui, name, descr, valid, draw_descr| {
-                default_property_draw_fn(ui, name, descr, valid, draw_descr, Box::new(cb))
-            }),
-            validation_result: Ok(()),
-        }
+        Self::from_checked_widget_fn(|ui| (cb(ui), Ok(())))
+    }
+
+    /// Like `from_widget_fn`, but the widget can fail validation while drawing, for values that cannot be validated up front.
+    fn from_checked_widget_fn(
+        cb: impl FnOnce(&mut Ui) -> (Response, Result<(), ValidationError>) + 'a,
+    ) -> Self {
+        Self::from_draw(PropertyDraw::Widget(Box::new(cb)))
+    }
+
+    /// Create a new property from a callback that adds a widget, like `from_widget_fn`, followed by the properties `children_cb` returns.
+    ///
+    /// The children are drawn in their own rows after the one of the widget, nested one level deeper.
+    /// `children_cb` is called after the widget is drawn. The property validates if the widget and all children do.
+    ///
+    /// ```
+    /// # use egui_property_editor::{Property, PropertyEditor};
+    /// # fn example(ui: &mut egui::Ui, on: &mut bool, brightness: &mut f32, warmth: &mut f32) {
+    /// PropertyEditor::new("editor")
+    ///     .named_property(
+    ///         "Light",
+    ///         Property::from_widget_fn_with_children(
+    ///             |ui| ui.checkbox(on, ""),
+    ///             |_ui| vec![("Brightness", brightness).into(), ("Warmth", warmth).into()],
+    ///         ),
+    ///     )
+    ///     .show(ui);
+    /// # }
+    /// ```
+    pub fn from_widget_fn_with_children(
+        widget_cb: impl FnOnce(&mut Ui) -> Response + 'a,
+        children_cb: impl FnOnce(&mut Ui) -> PropertyList<'a> + 'a,
+    ) -> Self {
+        Self::from_custom_draw_fn(Box::new(|ui, mut row| {
+            let mut valid =
+                default_property_draw_fn(ui, &mut row, Box::new(|ui| (widget_cb(ui), Ok(()))));
+            for child in children_cb(ui) {
+                valid &= row.draw_child(ui, child);
+            }
+            valid
+        }))
+    }
+
+    /// Create a new property that edits an integer with "-" and "+" buttons, instead of a `DragValue`.
+    ///
+    /// Each click changes the value by `step`, clamped to `range`. This works better than dragging on touch screens,
+    /// and for small bounded numbers. The steps are done in `T`, see `StepperInteger`, so they are exact over its whole range.
+    pub fn stepper<T: StepperInteger>(value: &'a mut T, step: T, range: RangeInclusive<T>) -> Self {
+        Self::from_widget_fn(move |ui| {
+            let (min, max) = (*range.start(), *range.end());
+            let current = *value;
+            let mut new = current;
+            let mut resp = ui
+                .horizontal(|ui| {
+                    let minus = ui.add_enabled(current > min, Button::new("-"));
+                    let label = ui.label(current.to_string());
+                    let plus = ui.add_enabled(current < max, Button::new("+"));
+                    if minus.clicked() {
+                        new = current.saturating_step_down(step).clamp(min, max);
+                    }
+                    if plus.clicked() {
+                        new = current.saturating_step_up(step).clamp(min, max);
+                    }
+                    minus.union(label).union(plus)
+                })
+                .inner;
+            if new != current {
+                *value = new;
+                resp.mark_changed();
+            }
+            value.clipboard_context_menu(&mut resp);
+            resp
+        })
+    }
+
+    /// Create a new property that edits a fixed size array, such as a color or a quaternion, with a `DragValue` per component.
+    ///
+    /// The components are laid out in rows of up to 4, so a `[f32; 16]` reads like a 4x4 matrix.
+    pub fn array<T: Numeric, const N: usize>(value: &'a mut [T; N]) -> Self {
+        Self::from_widget_fn(move |ui| array_ui(ui, value, None))
+    }
+
+    /// Like `Property::array`, but with a label in front of each component, such as "x", "y" and "z".
+    pub fn labeled_array<T: Numeric, const N: usize>(
+        value: &'a mut [T; N],
+        labels: [impl Into<WidgetText>; N],
+    ) -> Self {
+        let labels = labels.map(Into::into);
+        Self::from_widget_fn(move |ui| array_ui(ui, value, Some(labels)))
+    }
+
+    /// Create a new property that edits a normalized value, such as 0.25, as a percentage from 0% to 100%.
+    ///
+    /// See `PercentProperty` for other ranges and decimals.
+    pub fn percent(value: &'a mut f32) -> Self {
+        PercentProperty::new(value).into()
+    }
+
+    /// Create a new property that edits a shared `AtomicBool` with a checkbox, loading and storing with `Ordering::Relaxed`.
+    ///
+    /// See `AtomicProperty` for other orderings and the integer atomics.
+    pub fn atomic_bool(value: &'a AtomicBool) -> Self {
+        AtomicProperty::new(value).into()
+    }
+
+    /// Create a new property that edits a set of flags, such as a `bitflags` mask, with a checkbox per named flag.
+    ///
+    /// The checkboxes are stacked in the widget cell. A flag with several bits is checked if all of them are set,
+    /// and sets or clears all of them. Bits without a name are left as they are.
+    pub fn flags<T>(
+        value: &'a mut T,
+        flags: impl IntoIterator<Item = (impl Into<WidgetText>, T)>,
+    ) -> Self
+    where
+        T: Copy + PartialEq + BitAnd<Output = T> + BitOr<Output = T> + Not<Output = T>,
+    {
+        let flags: Vec<(WidgetText, T)> = flags
+            .into_iter()
+            .map(|(name, bits)| (name.into(), bits))
+            .collect();
+        Self::from_widget_fn(move |ui| {
+            ui.vertical(|ui| {
+                let mut resp: Option<Response> = None;
+                for (name, bits) in flags {
+                    let mut checked = *value & bits == bits;
+                    let flag_resp = ui.checkbox(&mut checked, name);
+                    if flag_resp.changed() {
+                        *value = if checked {
+                            *value | bits
+                        } else {
+                            *value & !bits
+                        };
+                    }
+                    resp = Some(match resp.take() {
+                        Some(resp) => resp.union(flag_resp),
+                        None => flag_resp,
+                    });
+                }
+                resp.unwrap_or_else(|| ui.label(""))
+            })
+            .inner
+        })
+    }
+
+    /// Create a new property that shows a button, and calls `on_click` when it is clicked.
+    ///
+    /// See `ButtonProperty` to disable the button.
+    pub fn button(label: impl Into<WidgetText>, on_click: impl FnOnce() + 'a) -> Self {
+        ButtonProperty::new(label, on_click).into()
+    }
+
+    /// Create a new property that shows a link, such as "Learn more", that opens `url` when clicked.
+    pub fn hyperlink(label: impl Into<WidgetText>, url: impl ToString) -> Self {
+        let label = label.into();
+        let url = url.to_string();
+        Self::from_widget_fn(move |ui| ui.hyperlink_to(label, url))
+    }
+
+    /// Create a new property that edits a URL, with an "Open" button next to it that opens it in the browser.
+    ///
+    /// Fails validation if the value is not empty, and not a URL such as `https://example.com` or `mailto:me@example.com`.
+    /// The button is disabled while it fails.
+    pub fn url(value: &'a mut String) -> Self {
+        Self::from_checked_widget_fn(move |ui| {
+            let valid = value.is_empty() || is_valid_url(value);
+            let resp = ui
+                .horizontal(|ui| {
+                    let mut resp = ui.add(
+                        TextEdit::singleline(value)
+                            .min_size(Vec2::X * 125.0)
+                            .clip_text(true),
+                    );
+                    value.clipboard_context_menu(&mut resp);
+                    let open = ui.add_enabled(valid && !value.is_empty(), Button::new("Open"));
+                    if open.clicked() {
+                        ui.ctx().open_url(OpenUrl::new_tab(value.as_str()));
+                    }
+                    resp
+                })
+                .inner;
+            let result = if valid {
+                Ok(())
+            } else {
+                Err(ValidationError::CustomWithMessage(
+                    "Not a URL, such as https://example.com".to_owned(),
+                ))
+            };
+            (resp, result)
+        })
+    }
+
+    /// Create a new property that edits a `String` with a dropdown of the `suggestions` that start with what was typed.
+    ///
+    /// Matching ignores case. The dropdown shows while the text edit has focus. A suggestion is picked by clicking it,
+    /// or by moving to it with the up and down arrow keys and pressing enter, which writes it into `value`.
+    /// Any other text can still be typed in; use `Property::validate` to only allow the suggestions.
+    pub fn autocomplete(value: &'a mut String, suggestions: Vec<String>) -> Self {
+        Self::from_widget_fn(move |ui| {
+            let id = property_widget_id(ui, "autocomplete");
+            let highlighted_id = id.with("highlighted suggestion");
+            let open_id = id.with("suggestions open");
+            let matches = |value: &str| {
+                let typed = value.to_lowercase();
+                suggestions
+                    .iter()
+                    .filter(|suggestion| {
+                        suggestion.as_str() != value
+                            && suggestion.to_lowercase().starts_with(&typed)
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            let focused = ui.memory(|m| m.has_focus(id));
+            let mut highlighted = ui.data(|d| d.get_temp::<usize>(highlighted_id));
+            let mut picked = None;
+            let before = matches(value);
+            if focused && !before.is_empty() {
+                // taken before the text edit sees them, which would move the cursor or give up the focus
+                ui.input_mut(|i| {
+                    let count = before.len();
+                    if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                        highlighted = Some(match highlighted {
+                            Some(h) if h + 1 < count => h + 1,
+                            _ => 0,
+                        });
+                    }
+                    if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                        highlighted = Some(match highlighted {
+                            Some(h) if h > 0 && h < count => h - 1,
+                            _ => count - 1,
+                        });
+                    }
+                    if let Some(h) = highlighted.filter(|h| *h < count)
+                        && i.consume_key(Modifiers::NONE, Key::Enter)
+                    {
+                        picked = Some(before[h].clone());
+                    }
+                });
+            }
+
+            let mut resp = ui.add(
+                TextEdit::singleline(value)
+                    .id(id)
+                    .min_size(Vec2::X * 125.0)
+                    .clip_text(true),
+            );
+            value.clipboard_context_menu(&mut resp);
+            if resp.changed() {
+                highlighted = None;
+            }
+
+            let after = matches(value);
+            // kept open for a frame after the focus is lost, so a click on a suggestion still lands
+            let was_open = ui.data(|d| d.get_temp::<bool>(open_id)).unwrap_or(false);
+            let has_focus = resp.has_focus();
+            let open = !after.is_empty() && picked.is_none() && (has_focus || was_open);
+            ui.data_mut(|d| d.insert_temp(open_id, has_focus));
+            Popup::from_response(&resp)
+                .id(id.with("suggestions"))
+                .open(open)
+                .width(resp.rect.width())
+                .show(|ui| {
+                    ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                        for (i, suggestion) in after.iter().enumerate() {
+                            let item =
+                                ui.selectable_label(highlighted == Some(i), suggestion.as_str());
+                            if highlighted == Some(i) {
+                                item.scroll_to_me(None);
+                            }
+                            if item.clicked() {
+                                picked = Some((*suggestion).clone());
+                            }
+                        }
+                    });
+                });
+
+            if let Some(suggestion) = picked {
+                *value = suggestion;
+                highlighted = None;
+                resp.mark_changed();
+                ui.memory_mut(|m| m.request_focus(id));
+            }
+            ui.data_mut(|d| match highlighted {
+                Some(h) => d.insert_temp(highlighted_id, h),
+                None => d.remove::<usize>(highlighted_id),
+            });
+            resp
+        })
+    }
+
+    /// Create a new property that edits the entries of a map, such as environment variables or headers.
+    ///
+    /// Each entry gets a row of its own below an "Add entry" button, sorted by key, with the key and a remove button in the
+    /// name cell, and the value as usual in the widget cell. An edited key is applied once its text edit loses focus.
+    /// Until then, and while it is already the key of another entry, the row keeps the typed text and fails validation.
+    /// New entries get a free key such as "key 2", and the default value.
+    pub fn map<V>(value: &'a mut HashMap<String, V>) -> Self
+    where
+        V: Default + 'a,
+        for<'b> &'b mut V: Into<Property<'b>>,
+    {
+        Self::from_custom_draw_fn(Box::new(move |ui, mut row| {
+            row.name_cell(ui);
+            let resp = row.widget_cell(ui, |ui| ui.button("Add entry"));
+            row.register_widget(&resp);
+            row.description_cell(ui);
+            ui.end_row();
+            let mut valid = row.show_validation_result(ui, &resp);
+
+            let read_only = row.settings.read_only;
+            let mut keys: Vec<String> = value.keys().cloned().collect();
+            keys.sort_unstable();
+            let mut renames = vec![];
+            let mut removals = vec![];
+            for key in &keys {
+                let Some(entry) = value.get_mut(key) else {
+                    continue;
+                };
+                // the typed key, while it differs from the one in the map
+                let scratch_id = row.row_id.with(("map key", key));
+                let scratch = ui.data(|d| d.get_temp::<String>(scratch_id));
+                let duplicate = scratch
+                    .as_ref()
+                    .filter(|scratch| *scratch != key && keys.contains(scratch))
+                    .map(|scratch| format!("\"{scratch}\" is already a key"));
+                let renames = &mut renames;
+                let removals = &mut removals;
+                let keys = &keys;
+                let key_widget = move |ui: &mut Ui, _| {
+                    ui.add_enabled_ui(!read_only, |ui| {
+                        ui.horizontal(|ui| {
+                            let mut text = scratch.unwrap_or_else(|| key.clone());
+                            let resp = ui.add(
+                                TextEdit::singleline(&mut text)
+                                    .id(scratch_id.with("edit"))
+                                    .desired_width(100.0)
+                                    .clip_text(true),
+                            );
+                            if ui.small_button("🗑").on_hover_text("Remove").clicked() {
+                                removals.push(key.clone());
+                            }
+                            if text == *key {
+                                ui.data_mut(|d| d.remove::<String>(scratch_id));
+                            } else if !resp.has_focus() && !keys.contains(&text) {
+                                renames.push((key.clone(), text));
+                                ui.data_mut(|d| d.remove::<String>(scratch_id));
+                            } else {
+                                ui.data_mut(|d| d.insert_temp(scratch_id, text));
+                            }
+                            resp
+                        })
+                        .inner
+                    })
+                    .inner
+                };
+                let property: Property = entry.into();
+                let mut property = property.name_widget(key_widget);
+                if let Some(duplicate) = duplicate {
+                    property =
+                        property.validate(|| Err(ValidationError::CustomWithMessage(duplicate)));
+                }
+                valid &= row.draw_child(ui, property);
+            }
+
+            let changed = resp.clicked() || !renames.is_empty() || !removals.is_empty();
+            for key in removals {
+                value.remove(&key);
+            }
+            for (old, new) in renames {
+                if !value.contains_key(&new)
+                    && let Some(entry) = value.remove(&old)
+                {
+                    value.insert(new, entry);
+                }
+            }
+            if resp.clicked() {
+                let key = (1..)
+                    .map(|i| {
+                        if i == 1 {
+                            "key".to_owned()
+                        } else {
+                            format!("key {i}")
+                        }
+                    })
+                    .find(|key| !value.contains_key(key))
+                    .unwrap_or_default();
+                value.insert(key, V::default());
+            }
+            if changed {
+                row.mark_changed();
+            }
+            valid
+        }))
     }
 
     /// Create a widget, but use custom drawing instead. Read the top level comment of `Property` (and, honestly, the source code of this file) for a detailed use of this.
     ///
     /// You probably do not wanna use this.
     pub fn from_custom_draw_fn(cb: Box<PropertyDrawFn<'a>>) -> Self {
+        Self::from_draw(PropertyDraw::Custom(cb))
+    }
+
+    /// Creates a new property with nothing but its way of drawing set.
+    fn from_draw(draw_fn: PropertyDraw<'a>) -> Self {
         Self {
             name: None,
             description: None,
-            draw_fn: cb,
+            name_tooltip: None,
+            draw_fn,
             validation_result: Ok(()),
+            prefix: None,
+            suffix: None,
+            font: None,
+            min_widget_width: None,
+            icon: None,
+            name_widget: None,
+            help: None,
+            on_error: None,
+            on_change: None,
+            debounce: None,
+            highlight: false,
+            highlight_color: None,
+            id_salt: None,
+            description_markdown: false,
+            no_description_cell: false,
+            #[cfg(feature = "serde")]
+            export: None,
+        }
+    }
+
+    /// Create a new property for `value`, like `Property::from`, that is part of `PropertyEditor::to_json` and `PropertyEditor::apply_json`.
+    ///
+    /// Use this to save and load the values of an editor, such as its settings. The value is serialized with serde,
+    /// under the name of the property, so only named properties take part.
+    #[cfg(feature = "serde")]
+    pub fn exported<T>(value: &'a mut T) -> Self
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + 'a,
+        for<'b> &'b mut T: Into<Property<'b>>,
+    {
+        // shared between drawing and the export, which both need the value
+        let value = Rc::new(RefCell::new(value));
+        let export_value = value.clone();
+        let import_value = value.clone();
+        Self {
+            export: Some(ExportShim {
+                export: Box::new(move || serde_json::to_value(&**export_value.borrow()).ok()),
+                import: Box::new(
+                    move |json| match serde_json::from_value::<T>(json.clone()) {
+                        Ok(new_value) => {
+                            **import_value.borrow_mut() = new_value;
+                            true
+                        }
+                        Err(_) => false,
+                    },
+                ),
+            }),
+            ..Self::from_custom_draw_fn(Box::new(move |ui, row| {
+                let mut value = value.borrow_mut();
+                (&mut **value).into().draw_into_row(ui, row)
+            }))
+        }
+    }
+
+    /// Create a new property for `value`, like `Property::from`, with a "⟲" button after the widget that resets it to `default`.
+    ///
+    /// Clicking the button opens a small popup asking `message`, with "Yes" and "No". Only "Yes" resets the value,
+    /// so use this for values that are tedious to enter again, such as a long key. The reset is applied when the property is drawn
+    /// in the next frame, and reported as a change then. Read-only editors do not show the button.
+    ///
+    /// ```
+    /// # use egui_property_editor::{Property, PropertyEditor};
+    /// # fn example(ui: &mut egui::Ui, key: &mut String) {
+    /// PropertyEditor::new("editor")
+    ///     .named_property(
+    ///         "API key",
+    ///         Property::with_reset_confirm(key, String::new(), "Clear the key?"),
+    ///     )
+    ///     .show(ui);
+    /// # }
+    /// ```
+    pub fn with_reset_confirm<T>(
+        value: &'a mut T,
+        default: T,
+        message: impl Into<WidgetText>,
+    ) -> Self
+    where
+        T: 'a,
+        for<'b> &'b mut T: Into<Property<'b>>,
+    {
+        let message = message.into();
+        Self::from_custom_draw_fn(Box::new(move |ui, mut row| {
+            let confirmed_id = row.row_id.with("reset confirmed");
+            if ui
+                .data_mut(|d| d.remove_temp::<bool>(confirmed_id))
+                .is_some()
+            {
+                *value = default;
+                row.mark_changed();
+            }
+            row.reset_confirm = Some(message);
+            (&mut *value).into().draw_into_row(ui, row)
+        }))
+    }
+
+    /// Draws this property into `row` of a wrapping property, which keeps its name, description and the like.
+    ///
+    /// The validation results of both count, and the prefix and suffix of this one are used if `row` has none.
+    fn draw_into_row(mut self, ui: &mut Ui, mut row: PropertyRow) -> bool {
+        row.validation_result = std::mem::replace(&mut row.validation_result, Ok(()))
+            .and(std::mem::replace(&mut self.validation_result, Ok(())));
+        row.prefix = row.prefix.take().or(self.prefix.take());
+        row.suffix = row.suffix.take().or(self.suffix.take());
+        match self.draw_fn {
+            PropertyDraw::Widget(widget_cb) => default_property_draw_fn(ui, &mut row, widget_cb),
+            PropertyDraw::Custom(cb) => cb(ui, row),
         }
     }
 
@@ -478,47 +3451,170 @@ impl<'a> Property<'a> {
         default: T,
         property_cb: impl FnOnce(&Ui, &'a mut T) -> PropertyList<'a> + 'a,
     ) -> Self {
-        let custom_draw_fn = move |ui: &mut Ui, name, description, _, draw_descr| -> bool {
-            let mut cb = value.is_some();
-            if let Some(name) = name {
-                ui.label(name);
-            } else {
-                ui.label("");
-            }
-            ui.checkbox(&mut cb, "");
-            if draw_descr {
-                if let Some(description) = description {
-                    ui.with_layout(Layout::top_down(Align::Min), |ui| {
-                        ui.style_mut().wrap_mode = Some(TextWrapMode::Wrap);
-                        ui.label(description)
-                    });
-                } else {
-                    ui.label("");
+        Self::new_optional_with(
+            name,
+            description,
+            value,
+            |_, _| default,
+            |_, _, _| {},
+            property_cb,
+            |ui, is_some| ui.checkbox(is_some, ""),
+            None,
+        )
+    }
+
+    /// Like `Property::new_optional`, but with a line of text next to the checkbox that sums up the value while it is `Some`.
+    ///
+    /// Use this to see the gist of the value at a glance, such as "800 × 600" for a size, even if the child properties are
+    /// scrolled out of view. The text of `summary_fn` is drawn with the current value each frame.
+    pub fn new_optional_summarized<T>(
+        name: impl Into<WidgetText>,
+        description: Option<impl Into<WidgetText>>,
+        value: &'a mut Option<T>,
+        default: T,
+        property_cb: impl FnOnce(&Ui, &'a mut T) -> PropertyList<'a> + 'a,
+        summary_fn: impl Fn(&T) -> WidgetText + 'a,
+    ) -> Self {
+        Self::new_optional_with(
+            name,
+            description,
+            value,
+            |_, _| default,
+            |_, _, _| {},
+            property_cb,
+            |ui, is_some| ui.checkbox(is_some, ""),
+            Some(Box::new(summary_fn)),
+        )
+    }
+
+    /// Like `Property::new_optional`, but unticking the checkbox keeps the value, and ticking it again restores it.
+    ///
+    /// The value is kept in egui memory while it is `None`, so `default` is only used the first time, and
+    /// the user does not lose their input when toggling. Since it is kept in memory, `T` must be `Clone + Send + Sync + 'static`.
+    pub fn new_optional_remembering<T: Clone + Send + Sync + 'static>(
+        name: impl Into<WidgetText>,
+        description: Option<impl Into<WidgetText>>,
+        value: &'a mut Option<T>,
+        default: T,
+        property_cb: impl FnOnce(&Ui, &'a mut T) -> PropertyList<'a> + 'a,
+    ) -> Self {
+        Self::new_optional_with(
+            name,
+            description,
+            value,
+            |ui, row_id| {
+                let id = row_id.with("remembered value");
+                ui.data_mut(|d| {
+                    let remembered = d.get_temp::<T>(id);
+                    d.remove::<T>(id);
+                    remembered
+                })
+                .unwrap_or(default)
+            },
+            |ui, row_id, last| {
+                ui.data_mut(|d| d.insert_temp(row_id.with("remembered value"), last))
+            },
+            property_cb,
+            |ui, is_some| ui.checkbox(is_some, ""),
+            None,
+        )
+    }
+
+    /// Like `Property::new_optional`, but with a combo box to choose between "None" and "Set", instead of a checkbox.
+    ///
+    /// This fits in better between other combo box properties, such as enums.
+    pub fn optional_combo<T>(
+        name: impl Into<WidgetText>,
+        description: Option<impl Into<WidgetText>>,
+        value: &'a mut Option<T>,
+        default: T,
+        property_cb: impl FnOnce(&Ui, &'a mut T) -> PropertyList<'a> + 'a,
+    ) -> Self {
+        Self::new_optional_with(
+            name,
+            description,
+            value,
+            |_, _| default,
+            |_, _, _| {},
+            property_cb,
+            |ui, is_some| {
+                let mut changed = false;
+                let mut resp = ComboBox::new(property_widget_id(ui, "is some"), "")
+                    .selected_text(if *is_some { "Set" } else { "None" })
+                    .show_ui(ui, |ui| {
+                        changed |= ui.selectable_value(is_some, false, "None").changed();
+                        changed |= ui.selectable_value(is_some, true, "Set").changed();
+                    })
+                    .response;
+                if changed {
+                    resp.mark_changed();
                 }
-            }
+                resp
+            },
+            None,
+        )
+    }
+
+    /// The shared part of the optional properties, with `toggle` drawing the widget that switches between `None` and `Some`.
+    ///
+    /// `default` gives the value when switching to `Some`, and `on_none` gets the last one when switching to `None`,
+    /// both along with the row id.
+    // private, and each caller passes most of these straight through
+    #[allow(clippy::too_many_arguments)]
+    fn new_optional_with<T>(
+        name: impl Into<WidgetText>,
+        description: Option<impl Into<WidgetText>>,
+        value: &'a mut Option<T>,
+        default: impl FnOnce(&Ui, Id) -> T + 'a,
+        on_none: impl FnOnce(&Ui, Id, T) + 'a,
+        property_cb: impl FnOnce(&Ui, &'a mut T) -> PropertyList<'a> + 'a,
+        toggle: fn(&mut Ui, &mut bool) -> Response,
+        summary_fn: Option<Box<SummaryFn<'a, T>>>,
+    ) -> Self {
+        let custom_draw_fn = move |ui: &mut Ui, mut row: PropertyRow| -> bool {
+            let row_id = row.row_id;
+            row.name_cell(ui);
+            let resp = row.widget_cell(ui, |ui| {
+                let toggle = |ui: &mut Ui, value: &mut Option<T>| {
+                    let mut cb = value.is_some();
+                    let resp = toggle(ui, &mut cb);
+                    if cb != value.is_some() {
+                        if cb {
+                            *value = Some(default(ui, row_id));
+                        } else if let Some(last) = value.take() {
+                            on_none(ui, row_id, last);
+                        }
+                    }
+                    resp
+                };
+                let Some(summary_fn) = &summary_fn else {
+                    return toggle(ui, value);
+                };
+                ui.horizontal(|ui| {
+                    let resp = toggle(ui, value);
+                    if let Some(value) = value.as_ref() {
+                        ui.label(summary_fn(value));
+                    }
+                    resp
+                })
+                .inner
+            });
+            row.register_widget(&resp);
+            row.description_cell(ui);
             ui.end_row();
 
-            if cb != value.is_some() {
-                if cb {
-                    *value = Some(default);
-                } else {
-                    *value = None;
-                }
-            }
-            let mut inner_validation_result = true;
+            let mut inner_validation_result = row.show_validation_result(ui, &resp);
             if let Some(val) = value {
                 for p in property_cb(ui, val) {
-                    inner_validation_result &= p.draw(ui, draw_descr);
+                    inner_validation_result &= row.draw_child(ui, p);
                 }
             }
 
             inner_validation_result
         };
         Self {
-            name: Some(name.into()),
             description: description.map(|x| x.into()),
-            draw_fn: Box::new(custom_draw_fn),
-            validation_result: Ok(()),
+            ..Self::from_custom_draw_fn(Box::new(custom_draw_fn)).name(name)
         }
     }
 
@@ -534,52 +3630,712 @@ impl<'a> Property<'a> {
     pub fn description(self, description: impl Into<WidgetText>) -> Self {
         Self {
             description: Some(description.into()),
+            description_markdown: false,
             ..self
         }
     }
 
-    /// Draw this property. Usually, you would not want to call this.
-    /// Here be dragons etc.
+    /// Builder-style function to set the description of this property to `markdown`, for richer help.
     ///
-    /// The only really valid place to call this in your code is if you have a custom drawing function, and let it produce additional properties.
-    /// You would then want to draw these after your initial `ui.end_row()`. See the `Property` docs for this as well.
-    pub fn draw(self, ui: &mut Ui, draw_description: bool) -> bool {
-        (self.draw_fn)(
-            ui,
-            self.name,
-            self.description,
-            self.validation_result,
-            draw_description,
-        )
+    /// A small subset is supported: `**strong**`, `*italics*`, `` `code` ``, `[links](https://example.com)`,
+    /// lists with `- ` and paragraphs separated by a blank line. A backslash escapes the next character.
+    /// The text wraps within the description column like a plain description, and links open in a new tab.
+    pub fn description_markdown(self, markdown: impl Into<String>) -> Self {
+        Self {
+            description: Some(markdown.into().into()),
+            description_markdown: true,
+            ..self
+        }
     }
-}
 
-/// An alias for `Vec<Property>` to recue `<>` in my code just a bit.
-pub type PropertyList<'a> = Vec<Property<'a>>;
+    /// Builder-style function to add validation to this property.
+    ///
+    /// Works like `ValidatedProperty::new`, but on any property, including custom widgets and the enum macros.
+    /// `validation_cb` is called immediately, so it cannot borrow the value this property edits; validate a copy instead.
+    /// If the property already failed validation, that error is kept and `validation_cb` is not called.
+    ///
+    /// ```
+    /// # use egui_property_editor::{Property, PropertyEditor, ValidationError};
+    /// # egui::__run_test_ui(|ui| {
+    ///     let mut value = 5;
+    ///     let is_even = value % 2 == 0;
+    ///     let _is_valid = PropertyEditor::new("editor")
+    ///         .property(Property::from(("must be even", &mut value)).validate(|| {
+    ///             if is_even {
+    ///                 Ok(())
+    ///             } else {
+    ///                 Err(ValidationError::CustomWithMessage("Must be even".to_string()))
+    ///             }
+    ///         }))
+    ///         .show(ui);
+    /// # });
+    /// ```
+    pub fn validate(self, validation_cb: impl FnOnce() -> Result<(), ValidationError>) -> Self {
+        Self {
+            validation_result: self.validation_result.and_then(|_| validation_cb()),
+            ..self
+        }
+    }
 
-/// Should validation fail, these are the ways it will do so.
-#[derive(Debug, Clone, PartialEq)]
-pub enum ValidationError {
-    /// A generic out of range message will be shown
-    OutOfRange,
-    /// A custom message
-    CustomWithMessage(String),
-}
+    /// Builder-style function to set a tooltip that is shown when hovering the name of this property.
+    pub fn name_tooltip(self, name_tooltip: impl Into<WidgetText>) -> Self {
+        Self {
+            name_tooltip: Some(name_tooltip.into()),
+            ..self
+        }
+    }
 
-impl Display for ValidationError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ValidationError::OutOfRange => {
-                write!(f, "The value is out of range.")
-            }
-            ValidationError::CustomWithMessage(msg) => {
-                write!(f, "{}", msg)
-            }
+    /// Builder-style function to add a label in front of the widget, inside the same cell.
+    pub fn prefix(self, prefix: impl Into<WidgetText>) -> Self {
+        Self {
+            prefix: Some(prefix.into()),
+            ..self
         }
     }
-}
 
-/// The callback type used by validation callbacks.
+    /// Builder-style function to add a label after the widget, inside the same cell. Useful for units, like "px" or "ms".
+    ///
+    /// Error highlighting still only outlines the widget itself.
+    pub fn suffix(self, suffix: impl Into<WidgetText>) -> Self {
+        Self {
+            suffix: Some(suffix.into()),
+            ..self
+        }
+    }
+
+    /// Builder-style function to set the font of the widget cell, such as `FontId::monospace(14.0)` or `TextStyle::Small`.
+    ///
+    /// Only the widget cell is affected, including prefix and suffix. The name and description keep their font.
+    pub fn font(self, font: impl Into<FontSelection>) -> Self {
+        Self {
+            font: Some(font.into()),
+            ..self
+        }
+    }
+
+    /// Builder-style function to keep the widget at least `width` wide, even if the widget column is narrower.
+    ///
+    /// Unlike `PropertyEditor::min_col_width`, this only affects this property, for widgets that need more room than
+    /// their neighbors. The cell grows to fit, and drag values, sliders, combo boxes and text edits are made at least this wide.
+    /// Prefix and suffix are drawn next to it.
+    pub fn min_widget_width(self, width: f32) -> Self {
+        Self {
+            min_widget_width: Some(width),
+            ..self
+        }
+    }
+
+    /// Builder-style function to draw an icon in front of the name, either a `char` such as an emoji, or an image.
+    ///
+    /// Images are scaled to the height of the text.
+    pub fn icon(self, icon: impl Into<PropertyIcon>) -> Self {
+        Self {
+            icon: Some(icon.into()),
+            ..self
+        }
+    }
+
+    /// Builder-style function to draw the name with a custom widget instead of a label.
+    ///
+    /// `name_widget` gets the name, or an empty text if there is none, and draws it into the name cell.
+    /// Use this to make names clickable, add badges, or highlight modified values.
+    /// The indent, icon and description "?" are still drawn around it, so the columns stay aligned.
+    pub fn name_widget(
+        self,
+        name_widget: impl FnOnce(&mut Ui, WidgetText) -> Response + 'a,
+    ) -> Self {
+        Self {
+            name_widget: Some(Box::new(name_widget)),
+            ..self
+        }
+    }
+
+    /// Builder-style function to show a "?" button after the widget, that opens a popup with `help` in it.
+    ///
+    /// Use this for help that does not fit a one line description, such as several paragraphs or a link.
+    /// The button is part of the widget cell, so it does not need a column of its own, and stays usable in read-only editors.
+    pub fn help(self, help: impl FnOnce(&mut Ui) + 'a) -> Self {
+        Self {
+            help: Some(Box::new(help)),
+            ..self
+        }
+    }
+
+    /// Builder-style function to show a failed validation of this property yourself.
+    ///
+    /// `on_error` is called with the error and the response of the widget, instead of the outline or inline message of the editor.
+    /// It is not called while the value is valid, or while the editor is read-only.
+    ///
+    /// ```
+    /// # use egui_property_editor::{Property, PropertyEditor, ValidatedProperty, ValidationError};
+    /// # fn example(ui: &mut egui::Ui, port: &mut u16) {
+    /// let port = ValidatedProperty::new(port, |port| {
+    ///     if **port < 1024 { Err(ValidationError::CustomWithMessage("Reserved port".into())) } else { Ok(()) }
+    /// });
+    /// PropertyEditor::new("editor")
+    ///     .named_property(
+    ///         "Port",
+    ///         Property::from(port).on_error(|ui, error, response| {
+    ///             let pos = response.rect.right_center() + egui::vec2(4.0, 0.0);
+    ///             ui.painter().text(pos, egui::Align2::LEFT_CENTER, "⚠", egui::FontId::default(), ui.visuals().warn_fg_color);
+    ///             response.clone().on_hover_text(error.to_string());
+    ///         }),
+    ///     )
+    ///     .show(ui);
+    /// # }
+    /// ```
+    pub fn on_error(
+        self,
+        on_error: impl FnOnce(&mut Ui, &ValidationError, &Response) + 'a,
+    ) -> Self {
+        Self {
+            on_error: Some(Box::new(on_error)),
+            ..self
+        }
+    }
+
+    /// Builder-style function to let the widget take the place of the description cell, for wide widgets that never have a description.
+    ///
+    /// The widget can then span the widget and description columns, without making the widget column of the other rows wider.
+    /// A description set anyway is not shown. Only matters while there is a description column. `Property::draw_in_cells`
+    /// leaves out the description, but keeps the widget in its own `Ui`.
+    pub fn no_description_cell(self, no_description_cell: bool) -> Self {
+        Self {
+            no_description_cell,
+            ..self
+        }
+    }
+
+    /// Builder-style function to tint the background of the whole row, including nested properties.
+    ///
+    /// Use this to mark values that changed since they were saved, or anything else your model knows about.
+    /// The tint is a faint version of the selection color, so it works on top of stripes. See `Property::highlight_color` to pick your own.
+    pub fn highlight(self, highlight: bool) -> Self {
+        Self { highlight, ..self }
+    }
+
+    /// Builder-style function to tint the background of the whole row with `color`. This turns on `Property::highlight`.
+    ///
+    /// Use a translucent color, so stripes still show through.
+    pub fn highlight_color(self, color: Color32) -> Self {
+        Self {
+            highlight: true,
+            highlight_color: Some(color),
+            ..self
+        }
+    }
+
+    /// Builder-style function to derive the id of the row of this property from `salt`, instead of its position.
+    ///
+    /// Without a salt, a row gets its id from its insertion index in the editor, or its index among the children
+    /// of its parent. Memory keyed by the row id, such as the state of combo boxes and popups (see `property_widget_id`
+    /// and `PropertyRow::id`), then stays at the position when properties are added, removed or reordered between frames,
+    /// and can end up with the wrong value. Use a salt that stays with the value, such as its key, for properties of dynamic lists.
+    ///
+    /// The salt does not change the auto ids egui gives widgets such as `TextEdit` or `DragValue`. Those still depend on what
+    /// was drawn before them, so an edit in progress can move to another row when the rows above it change.
+    pub fn id_salt(self, salt: impl Hash) -> Self {
+        Self {
+            id_salt: Some(Id::new(salt)),
+            ..self
+        }
+    }
+
+    /// Builder-style function to show the widget in monospace. Handy for hashes, hex values or code.
+    ///
+    /// Short for `font(TextStyle::Monospace)`.
+    pub fn monospace(self) -> Self {
+        self.font(TextStyle::Monospace)
+    }
+
+    /// Builder-style function to set a callback that is called when the value of this property changes.
+    ///
+    /// For properties with nested properties, like `Property::new_optional` or `enum_property!`, this also fires when
+    /// toggling the option, selecting a different variant, or changing one of the nested properties.
+    pub fn on_change(self, on_change: impl FnOnce() + 'a) -> Self {
+        Self {
+            on_change: Some(Box::new(on_change)),
+            ..self
+        }
+    }
+
+    /// Builder-style function to only report a change once the user stopped editing for `quiet_period`,
+    /// or moved the focus away from the widget.
+    ///
+    /// The value itself is still written on every keystroke, only `on_change` and the changed flag of the editor are delayed.
+    /// Use this for text fields that trigger expensive reactions, such as network requests.
+    pub fn debounce(self, quiet_period: Duration) -> Self {
+        Self {
+            debounce: Some(quiet_period),
+            ..self
+        }
+    }
+
+    /// Draw this property. Usually, you would not want to call this.
+    /// Here be dragons etc.
+    ///
+    /// The only really valid place to call this in your code is if you have a custom drawing function, and let it produce additional properties.
+    /// You would then want to draw these after your initial `ui.end_row()`. See the `Property` docs for this as well.
+    /// `PropertyRow::draw_child` does this for you, with the correct `depth`.
+    pub fn draw(self, ui: &mut Ui, settings: &EditorSettings, depth: usize) -> bool {
+        self.draw_tracked(ui, settings, depth).0
+    }
+
+    /// Draws the name, widget and description of this property into separate `Ui`s, instead of the cells of a grid.
+    ///
+    /// Use this to place properties in layouts of your own, such as a table with resizable columns.
+    /// The description is only drawn if `settings.draw_description` is set. Validation errors are shown in `widget_ui`.
+    /// Properties with a custom draw function, such as `enum_property!` or those with children, need the rows of a grid.
+    /// They are drawn as a small grid of their own into `widget_ui`, leaving the other two empty.
+    ///
+    /// Returns `true` if the property validated.
+    pub fn draw_in_cells(
+        mut self,
+        name_ui: &mut Ui,
+        widget_ui: &mut Ui,
+        description_ui: &mut Ui,
+        settings: &EditorSettings,
+    ) -> bool {
+        let changed = Cell::new(false);
+        let row_id = self.row_id(widget_ui);
+        let first_response = settings.responses.borrow().len();
+        let mut row = self.take_row(settings, 0, &changed, row_id);
+        let valid = match self.draw_fn {
+            PropertyDraw::Widget(widget_cb) => {
+                row.name_cell(name_ui);
+                let (resp, widget_result) = row.widget_cell(widget_ui, widget_cb);
+                row.validation_result =
+                    std::mem::replace(&mut row.validation_result, Ok(())).and(widget_result);
+                row.register_widget(&resp);
+                row.description_cell(description_ui);
+                row.show_validation_result(widget_ui, &resp)
+            }
+            PropertyDraw::Custom(cb) => {
+                Grid::new(row_id.with("cells"))
+                    .show(widget_ui, |ui| cb(ui, row))
+                    .inner
+            }
+        };
+        report_change(
+            widget_ui,
+            settings,
+            first_response,
+            row_id,
+            self.debounce,
+            self.on_change,
+            changed.get(),
+        );
+        valid
+    }
+
+    /// Where the memory of this row is kept. Stable if the property has an id salt.
+    fn row_id(&self, ui: &Ui) -> Id {
+        match self.id_salt {
+            Some(salt) => ui.id().with(salt).with("property row"),
+            None => ui.next_auto_id().with("property row"),
+        }
+    }
+
+    /// Moves everything a `PropertyRow` needs out of this property.
+    fn take_row<'s>(
+        &mut self,
+        settings: &'s EditorSettings,
+        depth: usize,
+        changed: &'s Cell<bool>,
+        row_id: Id,
+    ) -> PropertyRow<'s>
+    where
+        'a: 's,
+    {
+        let name_text = self
+            .name
+            .as_ref()
+            .map(|name| name.text().to_owned())
+            .unwrap_or_default();
+        PropertyRow {
+            name: self.name.take(),
+            description: self.description.take(),
+            name_tooltip: self.name_tooltip.take(),
+            validation_result: std::mem::replace(&mut self.validation_result, Ok(())),
+            prefix: self.prefix.take(),
+            suffix: self.suffix.take(),
+            font: self.font.take(),
+            min_widget_width: self.min_widget_width,
+            icon: self.icon.take(),
+            name_widget: self.name_widget.take().map(|f| f as Box<NameWidgetFn<'s>>),
+            help: self.help.take().map(|f| f as Box<HelpFn<'s>>),
+            on_error: self.on_error.take().map(|f| f as Box<ErrorFn<'s>>),
+            settings,
+            depth,
+            changed,
+            name_text,
+            row_id,
+            name_id: None,
+            salted: self.id_salt.is_some(),
+            children: Cell::new(0),
+            reset_confirm: None,
+            description_markdown: self.description_markdown,
+            no_description_cell: self.no_description_cell,
+            row_left: None,
+        }
+    }
+
+    /// Draws this property, returning if it validated, and if its value changed.
+    fn draw_tracked(
+        mut self,
+        ui: &mut Ui,
+        settings: &EditorSettings,
+        depth: usize,
+    ) -> (bool, bool) {
+        let changed = Cell::new(false);
+        let row_id = self.row_id(ui);
+        let first_response = settings.responses.borrow().len();
+        // reserved now, so the tint ends up behind the widgets
+        let top_left = ui.cursor().min;
+        let highlight = self.highlight.then(|| ui.painter().add(Shape::Noop));
+        let mut row = self.take_row(settings, depth, &changed, row_id);
+        row.row_left = Some(top_left.x);
+        let valid = match self.draw_fn {
+            PropertyDraw::Widget(widget_cb) => default_property_draw_fn(ui, &mut row, widget_cb),
+            PropertyDraw::Custom(cb) => cb(ui, row),
+        };
+        if let Some(shape) = highlight {
+            let color = self
+                .highlight_color
+                .unwrap_or(ui.visuals().selection.bg_fill.gamma_multiply(0.3));
+            ui.painter().set(
+                shape,
+                Shape::rect_filled(settings.row_rect(ui, top_left), 2, color),
+            );
+        }
+        let changed = report_change(
+            ui,
+            settings,
+            first_response,
+            row_id,
+            self.debounce,
+            self.on_change,
+            changed.get(),
+        );
+        (valid, changed)
+    }
+}
+
+/// How a `Property` is drawn.
+enum PropertyDraw<'a> {
+    /// A single widget, drawn into the cells by `default_property_draw_fn`
+    Widget(Box<CheckedWidgetFn<'a>>),
+    /// A custom draw function, that draws the cells and ends the row itself
+    Custom(Box<PropertyDrawFn<'a>>),
+}
+
+/// Reports the change of a property drawn with the widget responses from `first_response` on, calling `on_change`.
+///
+/// With a `debounce` period, the change might be reported later instead. Returns if the change is reported now.
+fn report_change(
+    ui: &Ui,
+    settings: &EditorSettings,
+    first_response: usize,
+    row_id: Id,
+    debounce: Option<Duration>,
+    on_change: Option<Box<dyn FnOnce() + '_>>,
+    mut changed: bool,
+) -> bool {
+    if let Some(quiet_period) = debounce {
+        let focused = settings.responses.borrow()[first_response..]
+            .iter()
+            .any(|r| r.response.has_focus());
+        changed = debounce_change(ui, row_id.with("debounce"), quiet_period, changed, focused);
+    }
+    if changed && let Some(on_change) = on_change {
+        on_change();
+    }
+    changed
+}
+
+/// Adds a wrapping label with the markdown subset of `Property::description_markdown`, and opens links that are clicked.
+fn markdown_label(ui: &mut Ui, markdown: &str) -> Response {
+    let (job, links) = markdown_job(ui.style(), markdown);
+    let (pos, galley, resp) = Label::new(job).sense(Sense::click()).layout_in_ui(ui);
+    let hovered_link = resp.hover_pos().and_then(|hover_pos| {
+        let index = galley.cursor_from_pos(hover_pos - pos).index;
+        links
+            .iter()
+            .find(|(range, _)| range.contains(&index))
+            .map(|(_, url)| url)
+    });
+    if let Some(url) = hovered_link {
+        ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+        if resp.clicked() {
+            ui.ctx().open_url(OpenUrl::new_tab(url));
+        }
+    }
+    ui.painter().galley(pos, galley, ui.visuals().text_color());
+    resp
+}
+
+/// Lays out `markdown` the way `Property::description_markdown` shows it, for descriptions drawn by custom draw functions.
+///
+/// Links are only underlined here, since a `LayoutJob` cannot be clicked. Markers without a counterpart are kept as text:
+/// ```
+/// # use egui_property_editor::markdown_layout_job;
+/// let style = egui::Style::default();
+/// // only a `[label]` directly followed by `(url)` is a link
+/// let job = markdown_layout_job(&style, "see [a] or [b](https://example.com)");
+/// assert_eq!(job.text, "see [a] or b");
+/// assert_eq!(job.sections.iter().filter(|s| s.format.underline.width > 0.0).count(), 1);
+/// // a lone `*` does not turn the rest of the paragraph italic
+/// let job = markdown_layout_job(&style, "2 * 3 is *six*");
+/// assert_eq!(job.text, "2 * 3 is six");
+/// let italics: Vec<_> = job.sections.iter().filter(|s| s.format.italics).collect();
+/// assert_eq!(italics.len(), 1);
+/// assert_eq!(&job.text[italics[0].byte_range.clone()], "six");
+/// ```
+pub fn markdown_layout_job(style: &egui::Style, markdown: &str) -> LayoutJob {
+    markdown_job(style, markdown).0
+}
+
+/// Lays out `markdown`, see `Property::description_markdown`. Also returns the char ranges of the links, along with their url.
+fn markdown_job(style: &egui::Style, markdown: &str) -> (LayoutJob, Vec<(Range<usize>, String)>) {
+    let body_font = FontSelection::Default.resolve(style);
+    let code_font = TextStyle::Monospace.resolve(style);
+    let visuals = &style.visuals;
+    let mut job = LayoutJob::default();
+    let mut links = Vec::new();
+    let mut chars = 0;
+    let mut append = |job: &mut LayoutJob, text: &str, format: TextFormat| {
+        let start = chars;
+        chars += text.chars().count();
+        job.append(text, 0.0, format);
+        start..chars
+    };
+    let text_format = |strong: bool, italics: bool| TextFormat {
+        font_id: body_font.clone(),
+        color: if strong {
+            visuals.strong_text_color()
+        } else {
+            visuals.text_color()
+        },
+        italics,
+        ..Default::default()
+    };
+    for (i, paragraph) in markdown.split("\n\n").enumerate() {
+        if i > 0 {
+            append(&mut job, "\n\n", text_format(false, false));
+        }
+        // lines of a paragraph are joined, list items keep their own line
+        let mut text = String::new();
+        for line in paragraph
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            if let Some(item) = line.strip_prefix("- ") {
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str("• ");
+                text.push_str(item);
+            } else {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(line);
+            }
+        }
+        let (mut strong, mut italics) = (false, false);
+        // an emphasis marker only opens if text follows it directly, and it is closed later in the paragraph
+        let opens = |after: &str, marker: &str| {
+            after.starts_with(|c: char| !c.is_whitespace()) && after.contains(marker)
+        };
+        let mut rest = text.as_str();
+        while let Some(c) = rest.chars().next() {
+            if let Some(after) = rest.strip_prefix("**")
+                && (strong || opens(after, "**"))
+            {
+                strong = !strong;
+                rest = after;
+            } else if c == '*' && !rest.starts_with("**") && (italics || opens(&rest[1..], "*")) {
+                italics = !italics;
+                rest = &rest[1..];
+            } else if c == '\\' && rest.len() > 1 {
+                let escaped = rest[1..].chars().next().map_or(1, char::len_utf8);
+                append(
+                    &mut job,
+                    &rest[1..1 + escaped],
+                    text_format(strong, italics),
+                );
+                rest = &rest[1 + escaped..];
+            } else if c == '`'
+                && let Some(end) = rest[1..].find('`')
+            {
+                let format = TextFormat {
+                    font_id: code_font.clone(),
+                    color: visuals.text_color(),
+                    background: visuals.code_bg_color,
+                    ..Default::default()
+                };
+                append(&mut job, &rest[1..1 + end], format);
+                rest = &rest[end + 2..];
+            } else if c == '['
+                && let Some((label, after)) = rest[1..].split_once(']')
+                && let Some(after) = after.strip_prefix('(')
+                && let Some((url, after)) = after.split_once(')')
+            {
+                let format = TextFormat {
+                    font_id: body_font.clone(),
+                    color: visuals.hyperlink_color,
+                    underline: Stroke::new(1.0, visuals.hyperlink_color),
+                    italics,
+                    ..Default::default()
+                };
+                let range = append(&mut job, label, format);
+                links.push((range, url.to_owned()));
+                rest = after;
+            } else {
+                // the first char is plain, even if it is one of the markers
+                let first = c.len_utf8();
+                let end = rest[first..]
+                    .find(['*', '\\', '`', '['])
+                    .map_or(rest.len(), |end| end + first);
+                append(&mut job, &rest[..end], text_format(strong, italics));
+                rest = &rest[end..];
+            }
+        }
+    }
+    (job, links)
+}
+
+/// The tag of the `Ui` the widget of a property with an id salt is drawn into. Its value is the id of the row.
+const ID_SALT_TAG: &str = "egui_property_editor id salt";
+
+/// The id for a widget of a property that needs memory of its own, such as a combo box.
+///
+/// Inside a property of an editor, this is derived from the id of its row and `name`, and the same every frame,
+/// see `Property::id_salt`. Everywhere else, this is the next auto id of `ui`. Use a different `name` for each widget of a property.
+///
+/// Only ids from this function (or `PropertyRow::id`) follow the row. Widgets drawn into the row without an explicit id
+/// get auto ids from egui, which depend on what was drawn before them in the frame.
+pub fn property_widget_id(ui: &Ui, name: impl Hash) -> Id {
+    ui.stack()
+        .iter()
+        .find_map(|frame| frame.tags().get_downcast::<Id>(ID_SALT_TAG))
+        .map_or_else(|| ui.next_auto_id(), |salt| salt.with(name))
+}
+
+/// Outlines the widget of `response` with the error style of the editor, and shows `error` when hovering it.
+fn paint_error_overlay(
+    ui: &Ui,
+    settings: &EditorSettings,
+    response: &Response,
+    error: &ValidationError,
+) {
+    let style = &settings.error_style;
+    let color = settings.error_color(ui);
+    ui.painter().rect_stroke(
+        response.interact_rect,
+        1,
+        Stroke::new(style.stroke_width, color),
+        StrokeKind::Outside,
+    );
+    if let Some(glyph) = &style.glyph {
+        ui.painter().text(
+            response.interact_rect.right_center(),
+            Align2::RIGHT_CENTER,
+            glyph,
+            FontId::monospace(response.interact_rect.height() * 0.9),
+            color,
+        );
+    }
+    response.clone().on_hover_text(error.to_string());
+}
+
+/// Delays reporting a change until `quiet_period` passed since the last one, or the row lost focus.
+///
+/// The time of the last unreported change is kept in temp data under `id`. Returns if the change should be reported now.
+fn debounce_change(ui: &Ui, id: Id, quiet_period: Duration, changed: bool, focused: bool) -> bool {
+    let now = ui.input(|i| i.time);
+    let last_change = if changed {
+        ui.data_mut(|d| d.insert_temp(id, now));
+        now
+    } else {
+        match ui.data(|d| d.get_temp::<f64>(id)) {
+            Some(last_change) => last_change,
+            None => return false,
+        }
+    };
+    let remaining = quiet_period.as_secs_f64() - (now - last_change);
+    if focused && remaining > 0.0 {
+        ui.ctx()
+            .request_repaint_after(Duration::from_secs_f64(remaining));
+        return false;
+    }
+    ui.data_mut(|d| d.remove_temp::<f64>(id));
+    true
+}
+
+/// An alias for `Vec<Property>` to recue `<>` in my code just a bit.
+pub type PropertyList<'a> = Vec<Property<'a>>;
+
+/// Should validation fail, these are the ways it will do so.
+#[derive(Debug, Clone)]
+pub enum ValidationError {
+    /// A generic out of range message will be shown
+    OutOfRange,
+    /// A custom message
+    CustomWithMessage(String),
+    /// A custom error type, for errors that carry more than a message. Its `Display` is shown.
+    ///
+    /// Create it with `ValidationError::custom`, and get the error back with `ValidationError::downcast_ref`.
+    /// To stay `Clone`, the error is shared. Two custom errors are only equal if they share the same error.
+    Custom(Arc<dyn Error + Send + Sync>),
+}
+
+impl ValidationError {
+    /// Wraps a custom error type in `ValidationError::Custom`.
+    pub fn custom(error: impl Error + Send + Sync + 'static) -> Self {
+        ValidationError::Custom(Arc::new(error))
+    }
+
+    /// Returns the custom error, if this is a `ValidationError::Custom` of type `E`.
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        match self {
+            ValidationError::Custom(error) => error.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for ValidationError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ValidationError::OutOfRange, ValidationError::OutOfRange) => true,
+            (ValidationError::CustomWithMessage(a), ValidationError::CustomWithMessage(b)) => {
+                a == b
+            }
+            (ValidationError::Custom(a), ValidationError::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::OutOfRange => {
+                write!(f, "The value is out of range.")
+            }
+            ValidationError::CustomWithMessage(msg) => {
+                write!(f, "{}", msg)
+            }
+            ValidationError::Custom(error) => {
+                write!(f, "{}", error)
+            }
+        }
+    }
+}
+
+/// The callback type used by validation callbacks.
 pub type ValidationCb<'a, T> = dyn FnOnce(&T) -> Result<(), ValidationError> + 'a;
 
 /// The helper struct for property validation.
@@ -638,183 +4394,2322 @@ impl<'a, T: 'a> ValidatedProperty<'a, T> {
         }
     }
 
-    /// Turns this `ValidatedProperty` into a `Property` with a custom widget.
-    pub fn with_widget_cb(self, cb: impl FnOnce(T, &mut Ui) -> Response + 'a) -> Property<'a> {
-        Property {
-            validation_result: (self.validation_cb)(&self.value),
-            ..Property::from_widget_fn(|ui| cb(self.value, ui))
-        }
+    /// Turns this `ValidatedProperty` into a `Property` with a custom widget.
+    pub fn with_widget_cb(self, cb: impl FnOnce(T, &mut Ui) -> Response + 'a) -> Property<'a> {
+        Property {
+            validation_result: (self.validation_cb)(&self.value),
+            ..Property::from_widget_fn(|ui| cb(self.value, ui))
+        }
+    }
+}
+
+/// The callback type used by `TypedProperty`. Unlike `ValidationCb`, it can be called more than once.
+pub type TypedValidationCb<'a, T> = dyn Fn(&T) -> Result<(), ValidationError> + 'a;
+
+/// Like `ValidatedProperty`, but the value stays accessible, and can be validated without drawing anything.
+///
+/// Use this to test validation logic without an egui context, or to validate values changed by code.
+/// Convert it into a `Property` to draw it, which validates it one more time.
+///
+/// ```
+/// # use egui_property_editor::{TypedProperty, ValidationError};
+/// let mut port = 0u16;
+/// let mut property = TypedProperty::new(&mut port, |port| {
+///     if *port == 0 {
+///         Err(ValidationError::OutOfRange)
+///     } else {
+///         Ok(())
+///     }
+/// });
+/// assert_eq!(property.validate(), Err(ValidationError::OutOfRange));
+/// *property.value_mut() = 8080;
+/// let (port, result) = property.evaluate();
+/// assert_eq!((*port, result), (8080, Ok(())));
+/// ```
+pub struct TypedProperty<'a, T> {
+    /// The value to be validated
+    value: &'a mut T,
+    /// The callback that validates `value`
+    validation_cb: Box<TypedValidationCb<'a, T>>,
+}
+
+impl<'a, T: 'a> TypedProperty<'a, T> {
+    /// Creates a new property, that always validates.
+    pub fn unvalidated(value: &'a mut T) -> Self {
+        Self::new(value, |_| Ok(()))
+    }
+
+    /// Creates a new validated property. Unlike `ValidatedProperty::new`, `validation_cb` is only called when needed.
+    pub fn new(
+        value: &'a mut T,
+        validation_cb: impl Fn(&T) -> Result<(), ValidationError> + 'a,
+    ) -> Self {
+        Self {
+            value,
+            validation_cb: Box::new(validation_cb),
+        }
+    }
+
+    /// The current value.
+    pub fn value(&self) -> &T {
+        self.value
+    }
+
+    /// The current value, to change it before validating or drawing.
+    pub fn value_mut(&mut self) -> &mut T {
+        self.value
+    }
+
+    /// Validates the current value, without drawing anything.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        (self.validation_cb)(self.value)
+    }
+
+    /// Validates the current value, and gives it back together with the result, without drawing anything.
+    pub fn evaluate(self) -> (&'a mut T, Result<(), ValidationError>) {
+        let result = self.validate();
+        (self.value, result)
+    }
+
+    /// Turns this `TypedProperty` into a `Property` with a custom widget.
+    pub fn with_widget_cb(
+        self,
+        cb: impl FnOnce(&'a mut T, &mut Ui) -> Response + 'a,
+    ) -> Property<'a> {
+        let (value, validation_result) = self.evaluate();
+        Property {
+            validation_result,
+            ..Property::from_widget_fn(|ui| cb(value, ui))
+        }
+    }
+}
+
+/// A helper wrapper that remembers the original value of a property, so changes can be listed and reverted, like in a dialog with "Cancel".
+///
+/// The original is a clone of the value, taken the first time the property is drawn, and kept in egui memory under the id of its row.
+/// So use `Property::id_salt` if properties are added or removed between frames.
+/// `PropertyEditor::show_with_dirty` lists the properties that differ from their original, `PropertyEditor::revert_all` restores the originals,
+/// and `PropertyEditor::accept_all` takes the current values as the new originals, for example after saving.
+/// With `PropertyEditor::keyboard_commit`, escape also reverts the last edit.
+pub struct Tracked<'a, T> {
+    /// The value to edit
+    value: &'a mut T,
+}
+
+impl<'a, T> Tracked<'a, T> {
+    /// Tracks changes of `value` from the value it had when it was first drawn.
+    pub fn new(value: &'a mut T) -> Self {
+        Self { value }
+    }
+}
+
+impl<'a, T> From<Tracked<'a, T>> for Property<'a>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+    for<'b> &'b mut T: Into<Property<'b>>,
+{
+    fn from(tracked: Tracked<'a, T>) -> Self {
+        let value = tracked.value;
+        Self::from_custom_draw_fn(Box::new(move |ui, row| {
+            let settings = row.settings;
+            let original_id = row.row_id.with("tracked original");
+            let original = ui.data(|d| d.get_temp::<T>(original_id));
+            let original = match original {
+                Some(original) if !settings.accept_tracked => original,
+                _ => {
+                    ui.data_mut(|d| d.insert_temp(original_id, value.clone()));
+                    value.clone()
+                }
+            };
+            if settings.revert_tracked && *value != original {
+                *value = original.clone();
+                row.mark_changed();
+            }
+            let changed = row.changed;
+            let pre_edit_id = row.row_id.with("tracked pre edit");
+            let first_response = settings.responses.borrow().len();
+            let before = settings.keyboard_commit.then(|| value.clone());
+            let valid = (&mut *value).into().draw_into_row(ui, row);
+            if let Some(before) = before {
+                let responses = &settings.responses.borrow()[first_response..];
+                let focused = responses.iter().any(|r| r.response.has_focus());
+                // egui takes the focus on escape before anything is drawn
+                let escaped = ui.input(|i| i.key_pressed(Key::Escape))
+                    && responses.iter().any(|r| r.response.lost_focus());
+                if escaped {
+                    let pre_edit = ui.data_mut(|d| {
+                        let pre_edit = d.get_temp::<T>(pre_edit_id);
+                        d.remove::<T>(pre_edit_id);
+                        pre_edit
+                    });
+                    if let Some(pre_edit) = pre_edit
+                        && *value != pre_edit
+                    {
+                        *value = pre_edit;
+                        changed.set(true);
+                    }
+                } else if focused {
+                    let gained_focus = responses.iter().any(|r| r.response.gained_focus());
+                    if gained_focus || ui.data(|d| d.get_temp::<T>(pre_edit_id)).is_none() {
+                        ui.data_mut(|d| d.insert_temp(pre_edit_id, before));
+                    }
+                } else if responses.iter().any(|r| r.response.lost_focus()) {
+                    ui.data_mut(|d| d.remove::<T>(pre_edit_id));
+                }
+            }
+            if *value != original {
+                settings.dirty.set(true);
+            }
+            valid
+        }))
+    }
+}
+
+/// Conversion of a value to and from text, used for the "Copy" and "Paste" context menu of properties.
+///
+/// Implemented for the types that have a built-in `From<&mut T> for Property`.
+pub trait PropertyClipboard {
+    /// Turns the value into text for the clipboard.
+    fn to_clipboard(&self) -> String;
+
+    /// Parses `text` and overwrites the value with it. Returns `true` if the value was overwritten.
+    ///
+    /// Text that cannot be parsed must leave the value untouched.
+    fn paste_clipboard(&mut self, text: &str) -> bool;
+
+    /// Adds a context menu with "Copy" and "Paste" to `response`.
+    ///
+    /// egui cannot read the system clipboard on demand, so "Paste" uses the last value copied by any property.
+    /// Copying also puts the text into the system clipboard. Marks `response` as changed when pasting.
+    fn clipboard_context_menu(&mut self, response: &mut Response) {
+        let clipboard_id = Id::new("egui_property_editor_clipboard");
+        let mut changed = false;
+        response.context_menu(|ui| {
+            if ui.button("Copy").clicked() {
+                let text = self.to_clipboard();
+                ui.ctx().copy_text(text.clone());
+                ui.data_mut(|d| d.insert_temp(clipboard_id, text));
+            }
+            let clipboard = ui.data(|d| d.get_temp::<String>(clipboard_id));
+            let paste = ui.add_enabled(clipboard.is_some(), Button::new("Paste"));
+            if paste.clicked()
+                && let Some(text) = clipboard
+            {
+                changed = self.paste_clipboard(&text);
+            }
+        });
+        if changed {
+            response.mark_changed();
+        }
+    }
+}
+
+/// A helper macro to implement `PropertyClipboard` with `Display` and `FromStr`.
+macro_rules! clipboard_impl {
+    ($($t:ty),*) => {
+        $(
+            impl PropertyClipboard for $t {
+                fn to_clipboard(&self) -> String {
+                    self.to_string()
+                }
+
+                fn paste_clipboard(&mut self, text: &str) -> bool {
+                    match text.trim().parse() {
+                        Ok(value) => {
+                            *self = value;
+                            true
+                        }
+                        Err(_) => false,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+clipboard_impl!(
+    u8, i8, u16, i16, u32, i32, u64, i64, i128, u128, usize, isize, f32, f64, bool, IpAddr,
+    SocketAddr
+);
+
+/// An integer type that `Property::stepper` can step through, implemented for the primitive integers.
+pub trait StepperInteger: Copy + Ord + Display + PropertyClipboard {
+    /// Adds `step`, saturating at the limits of the type.
+    fn saturating_step_up(self, step: Self) -> Self;
+
+    /// Subtracts `step`, saturating at the limits of the type.
+    fn saturating_step_down(self, step: Self) -> Self;
+}
+
+/// A helper macro to implement `StepperInteger` with the saturating arithmetic of the integers.
+macro_rules! stepper_integer_impl {
+    ($($t:ty),*) => {
+        $(
+            impl StepperInteger for $t {
+                fn saturating_step_up(self, step: Self) -> Self {
+                    self.saturating_add(step)
+                }
+
+                fn saturating_step_down(self, step: Self) -> Self {
+                    self.saturating_sub(step)
+                }
+            }
+        )*
+    };
+}
+
+stepper_integer_impl!(
+    u8, i8, u16, i16, u32, i32, u64, i64, i128, u128, usize, isize
+);
+
+impl PropertyClipboard for String {
+    fn to_clipboard(&self) -> String {
+        self.clone()
+    }
+
+    fn paste_clipboard(&mut self, text: &str) -> bool {
+        text.clone_into(self);
+        true
+    }
+}
+
+impl PropertyClipboard for Box<str> {
+    fn to_clipboard(&self) -> String {
+        self.to_string()
+    }
+
+    fn paste_clipboard(&mut self, text: &str) -> bool {
+        *self = text.into();
+        true
+    }
+}
+
+impl PropertyClipboard for Cow<'_, str> {
+    fn to_clipboard(&self) -> String {
+        self.to_string()
+    }
+
+    fn paste_clipboard(&mut self, text: &str) -> bool {
+        *self = Cow::Owned(text.to_owned());
+        true
+    }
+}
+
+impl PropertyClipboard for Duration {
+    /// Durations are copied as seconds.
+    fn to_clipboard(&self) -> String {
+        self.as_secs_f64().to_string()
+    }
+
+    fn paste_clipboard(&mut self, text: &str) -> bool {
+        match text
+            .trim()
+            .parse()
+            .ok()
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        {
+            Some(value) => {
+                *self = value;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'a> From<&'a mut String> for Property<'a> {
+    fn from(value: &'a mut String) -> Self {
+        StringProperty::new(value).into()
+    }
+}
+
+/// Edited through a `String`, and written back as a new `Box<str>` once editing ends, that is when the text edit loses focus.
+///
+/// The text is copied into a `String` every frame. While it is edited, that `String` is kept in egui memory,
+/// so there is a single allocation of a `Box<str>` per edit, instead of one per keystroke.
+/// Accordingly, the change is reported once the value is written back.
+impl<'a> From<&'a mut Box<str>> for Property<'a> {
+    fn from(value: &'a mut Box<str>) -> Self {
+        Self::from_widget_fn(move |ui| {
+            let scratch_id = property_widget_id(ui, "boxed str").with("scratch");
+            let mut text = ui
+                .data(|d| d.get_temp::<String>(scratch_id))
+                .unwrap_or_else(|| value.to_string());
+            let mut resp = ui.add(
+                TextEdit::singleline(&mut text)
+                    .clip_text(true)
+                    .min_size(Vec2::X * 125.0),
+            );
+            // only writing back counts as a change
+            resp.flags.remove(Flags::CHANGED);
+            if resp.has_focus() {
+                ui.data_mut(|d| d.insert_temp(scratch_id, text));
+            } else {
+                ui.data_mut(|d| d.remove_temp::<String>(scratch_id));
+                if *text != **value {
+                    *value = text.into_boxed_str();
+                    resp.mark_changed();
+                }
+            }
+            value.clipboard_context_menu(&mut resp);
+            resp
+        })
+    }
+}
+
+/// Borrowed text is copied into a `String` every frame, and becomes `Cow::Owned` with the first edit.
+/// From then on, the owned `String` is edited directly, like `&mut String`.
+impl<'a, 'b> From<&'a mut Cow<'b, str>> for Property<'a> {
+    fn from(value: &'a mut Cow<'b, str>) -> Self {
+        Self::from_widget_fn(move |ui| {
+            let mut resp = match value {
+                Cow::Owned(text) => ui.add(
+                    TextEdit::singleline(text)
+                        .clip_text(true)
+                        .min_size(Vec2::X * 125.0),
+                ),
+                Cow::Borrowed(borrowed) => {
+                    let mut text = (*borrowed).to_owned();
+                    let resp = ui.add(
+                        TextEdit::singleline(&mut text)
+                            .clip_text(true)
+                            .min_size(Vec2::X * 125.0),
+                    );
+                    if resp.changed() {
+                        *value = Cow::Owned(text);
+                    }
+                    resp
+                }
+            };
+            value.clipboard_context_menu(&mut resp);
+            resp
+        })
+    }
+}
+
+/// A helper wrapper to edit a `String` with some of the options of `egui::TextEdit`.
+///
+/// `&mut String` also converts into a `Property` directly, as a clipped single line edit.
+pub struct StringProperty<'a> {
+    /// The value to edit
+    value: &'a mut String,
+    /// If the text edit spans multiple lines
+    multiline: bool,
+    /// Shown while the value is empty
+    hint_text: Option<WidgetText>,
+    /// The maximum number of characters
+    char_limit: Option<usize>,
+    /// If the characters are hidden
+    password: bool,
+}
+
+impl<'a> StringProperty<'a> {
+    /// Creates a new string property, as a single line edit.
+    pub fn new(value: &'a mut String) -> Self {
+        Self {
+            value,
+            multiline: false,
+            hint_text: None,
+            char_limit: None,
+            password: false,
+        }
+    }
+
+    /// Set if the text edit spans multiple lines. Enter then adds a new line instead of losing focus.
+    ///
+    /// The row grows with the text edit, see `PropertyEditor::cell_valign` to align the name against it.
+    pub fn multiline(self, multiline: bool) -> Self {
+        Self { multiline, ..self }
+    }
+
+    /// Set a text that is shown while the value is empty.
+    pub fn hint_text(self, hint_text: impl Into<WidgetText>) -> Self {
+        Self {
+            hint_text: Some(hint_text.into()),
+            ..self
+        }
+    }
+
+    /// Set the maximum number of characters. Longer input is cut off.
+    pub fn char_limit(self, char_limit: usize) -> Self {
+        Self {
+            char_limit: Some(char_limit),
+            ..self
+        }
+    }
+
+    /// Set if the characters are hidden, for passwords and other secrets. This also removes the copy and paste menu.
+    pub fn password(self, password: bool) -> Self {
+        Self { password, ..self }
+    }
+}
+
+impl<'a> From<StringProperty<'a>> for Property<'a> {
+    fn from(property: StringProperty<'a>) -> Self {
+        Self::from_widget_fn(move |ui| {
+            let StringProperty {
+                value,
+                multiline,
+                hint_text,
+                char_limit,
+                password,
+            } = property;
+            // values set from code or pasted from the context menu can be longer than the limit
+            let mut truncated = false;
+            if let Some(char_limit) = char_limit
+                && let Some((end, _)) = value.char_indices().nth(char_limit)
+            {
+                value.truncate(end);
+                truncated = true;
+            }
+            let mut edit = if multiline {
+                TextEdit::multiline(value)
+            } else {
+                TextEdit::singleline(value).clip_text(true)
+            }
+            .min_size(Vec2::X * 125.0)
+            .password(password);
+            if let Some(hint_text) = hint_text {
+                edit = edit.hint_text(hint_text);
+            }
+            if let Some(char_limit) = char_limit {
+                edit = edit.char_limit(char_limit);
+            }
+            let mut resp = ui.add(edit);
+            if truncated {
+                resp.mark_changed();
+            }
+            if !password {
+                value.clipboard_context_menu(&mut resp);
+            }
+            resp
+        })
+    }
+}
+
+/// A helper macro to add `From<T>` to `Property` for primitive types that allow `egui::DragValue`.
+macro_rules! numeric_impl {
+    ($t:ty) => {
+        impl<'a> From<&'a mut $t> for Property<'a>
+        {
+            fn from(value: &'a mut $t) -> Self {
+                Self::from_widget_fn(|ui| {
+                    let mut resp = ui.add(DragValue::new(value));
+                    value.clipboard_context_menu(&mut resp);
+                    resp
+                })
+            }
+        }
+    };
+    ($t:tt,$($rest:tt)*) => {
+        numeric_impl!($t);
+        numeric_impl!($($rest)*);
+    }
+}
+
+numeric_impl!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize);
+
+/// A helper macro to add `From<T>` to `Property` for floats, which go through `FloatProperty` to show NaN and infinity.
+macro_rules! float_impl {
+    ($($t:ty),*) => {
+        $(
+            impl<'a> From<&'a mut $t> for Property<'a> {
+                fn from(value: &'a mut $t) -> Self {
+                    FloatProperty::new(value).into()
+                }
+            }
+        )*
+    };
+}
+
+float_impl!(f32, f64);
+
+/// A helper wrapper to edit a float that might not be a finite number, such as the result of a division by zero.
+///
+/// `&mut f32` and `&mut f64` use this. A `DragValue` shows such values as a plain number, or not at all.
+/// Here, they are shown as "NaN", "∞" or "-∞" in the warning color instead. Typing in a number replaces them as usual.
+pub struct FloatProperty<'a, T> {
+    /// The value to edit
+    value: &'a mut T,
+    /// If non-finite values fail validation
+    finite_only: bool,
+}
+
+impl<'a, T: Numeric> FloatProperty<'a, T> {
+    /// Creates a new float property, that shows non-finite values, but lets them pass validation.
+    pub fn new(value: &'a mut T) -> Self {
+        Self {
+            value,
+            finite_only: false,
+        }
+    }
+
+    /// Set to `true` to fail validation while the value is NaN or infinite.
+    pub fn finite_only(self, finite_only: bool) -> Self {
+        Self {
+            finite_only,
+            ..self
+        }
+    }
+}
+
+impl<'a, T: Numeric + PropertyClipboard> From<FloatProperty<'a, T>> for Property<'a> {
+    fn from(property: FloatProperty<'a, T>) -> Self {
+        Self::from_checked_widget_fn(move |ui| {
+            let FloatProperty { value, finite_only } = property;
+            let finite = value.to_f64().is_finite();
+            let mut resp = if finite {
+                ui.add(DragValue::new(&mut *value))
+            } else {
+                ui.scope(|ui| {
+                    let warn_color = ui.visuals().warn_fg_color;
+                    let widgets = &mut ui.visuals_mut().widgets;
+                    for visuals in [
+                        &mut widgets.inactive,
+                        &mut widgets.hovered,
+                        &mut widgets.active,
+                    ] {
+                        visuals.fg_stroke.color = warn_color;
+                    }
+                    ui.add(DragValue::new(&mut *value).custom_formatter(|value, _| {
+                        if value.is_nan() {
+                            "NaN".to_owned()
+                        } else if value > 0.0 {
+                            "∞".to_owned()
+                        } else {
+                            "-∞".to_owned()
+                        }
+                    }))
+                    .on_hover_text("Not a finite number")
+                })
+                .inner
+            };
+            value.clipboard_context_menu(&mut resp);
+            let result = if finite_only && !value.to_f64().is_finite() {
+                Err(ValidationError::CustomWithMessage(
+                    "Not a finite number".to_owned(),
+                ))
+            } else {
+                Ok(())
+            };
+            (resp, result)
+        })
+    }
+}
+
+/// A helper wrapper to edit an atomic, such as a runtime flag shared between threads, through a shared reference.
+///
+/// The value is loaded once per frame and drawn like the plain value would be, a checkbox for `AtomicBool`, and a `DragValue` for the integers.
+/// It is only stored back when the user changed it, so changes from other threads in the meantime are not overwritten.
+/// `&AtomicBool`, `&AtomicU32` and the other atomics turn into a property with `Ordering::Relaxed` on their own.
+///
+/// ```
+/// # use egui_property_editor::{AtomicProperty, PropertyEditor};
+/// # use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+/// # fn example(ui: &mut egui::Ui, paused: &AtomicBool, workers: &AtomicU32) {
+/// PropertyEditor::new("editor")
+///     .named_property("Paused", paused)
+///     .named_property("Workers", AtomicProperty::new(workers).ordering(Ordering::Acquire, Ordering::Release))
+///     .show(ui);
+/// # }
+/// ```
+pub struct AtomicProperty<'a, A> {
+    /// The atomic to edit
+    value: &'a A,
+    /// The ordering the value is loaded with
+    load: Ordering,
+    /// The ordering a changed value is stored with
+    store: Ordering,
+}
+
+impl<'a, A> AtomicProperty<'a, A> {
+    /// Creates a new atomic property, that loads and stores with `Ordering::Relaxed`.
+    pub fn new(value: &'a A) -> Self {
+        Self {
+            value,
+            load: Ordering::Relaxed,
+            store: Ordering::Relaxed,
+        }
+    }
+
+    /// Set the orderings the value is loaded and stored with.
+    ///
+    /// Panics while drawing if `load` is `Release` or `AcqRel`, or `store` is `Acquire` or `AcqRel`, like the atomics do.
+    pub fn ordering(self, load: Ordering, store: Ordering) -> Self {
+        Self {
+            load,
+            store,
+            ..self
+        }
+    }
+}
+
+/// A helper macro to add `From<AtomicProperty<A>>` and `From<&A>` to `Property` for atomics, drawn like their plain value.
+macro_rules! atomic_impl {
+    ($($atomic:ty),*) => {
+        $(
+            impl<'a> From<AtomicProperty<'a, $atomic>> for Property<'a> {
+                fn from(property: AtomicProperty<'a, $atomic>) -> Self {
+                    Self::from_custom_draw_fn(Box::new(move |ui, row| {
+                        let loaded = property.value.load(property.load);
+                        let mut value = loaded;
+                        let valid = Property::from(&mut value).draw_into_row(ui, row);
+                        if value != loaded {
+                            property.value.store(value, property.store);
+                        }
+                        valid
+                    }))
+                }
+            }
+
+            impl<'a> From<&'a $atomic> for Property<'a> {
+                fn from(value: &'a $atomic) -> Self {
+                    AtomicProperty::new(value).into()
+                }
+            }
+        )*
+    };
+}
+
+atomic_impl!(
+    AtomicBool,
+    AtomicU8,
+    AtomicI8,
+    AtomicU16,
+    AtomicI16,
+    AtomicU32,
+    AtomicI32,
+    AtomicU64,
+    AtomicI64,
+    AtomicUsize,
+    AtomicIsize
+);
+
+/// A `RefCell` is drawn like the value in it, borrowed mutably only while the property is drawn.
+///
+/// Use this for values that are also read by closures kept in the editor, such as the ones of `PropertyEditor::validate_all`.
+impl<'a, T> From<&'a RefCell<T>> for Property<'a>
+where
+    T: 'a,
+    for<'b> &'b mut T: Into<Property<'b>>,
+{
+    fn from(value: &'a RefCell<T>) -> Self {
+        Self::from_custom_draw_fn(Box::new(move |ui, row| {
+            (&mut *value.borrow_mut()).into().draw_into_row(ui, row)
+        }))
+    }
+}
+
+/// A helper wrapper to edit a number with more control than the plain `DragValue` of `&mut T`.
+///
+/// Can limit the value to a range, show a slider instead of a `DragValue`, and edit logarithmically.
+/// The latter is great for values spanning several orders of magnitude, such as frequencies from 20 Hz to 20 kHz.
+pub struct NumericProperty<'a, T> {
+    /// The value to edit
+    value: &'a mut T,
+    /// The range dragging stays in
+    range: Option<RangeInclusive<T>>,
+    /// Show a slider instead of a `DragValue`
+    slider: bool,
+    /// Edit logarithmically
+    logarithmic: bool,
+    /// The smallest positive value logarithmic editing distinguishes
+    smallest_positive: Option<f64>,
+    /// The increment dragged values snap to
+    snap: Option<T>,
+    /// If typed and existing values are clamped to the range as well
+    clamp_to_range: bool,
+    /// If values outside the range fail validation
+    reject_out_of_range: bool,
+    /// The factor from the stored to the displayed unit, and the label of the displayed unit
+    scale: Option<(f64, String)>,
+    /// If held modifier keys change the drag speed
+    precision_modifiers: bool,
+}
+
+impl<'a, T: Numeric> NumericProperty<'a, T> {
+    /// Creates a new numeric property, with a linear `DragValue` over the whole range of `T`.
+    pub fn new(value: &'a mut T) -> Self {
+        Self {
+            value,
+            range: None,
+            slider: false,
+            logarithmic: false,
+            smallest_positive: None,
+            snap: None,
+            clamp_to_range: false,
+            reject_out_of_range: false,
+            scale: None,
+            precision_modifiers: false,
+        }
+    }
+
+    /// Set the range of the value. Dragging, and the slider, stay within it.
+    ///
+    /// Typed values and values that are out of range to begin with, such as ones loaded from a file, are kept.
+    /// See `NumericProperty::clamp_to_range` to correct them, or `NumericProperty::reject_out_of_range` to flag them.
+    pub fn range(self, range: RangeInclusive<T>) -> Self {
+        Self {
+            range: Some(range),
+            ..self
+        }
+    }
+
+    /// Set to `true` to show a slider instead of a `DragValue`. Best combined with `NumericProperty::range`.
+    pub fn slider(self, slider: bool) -> Self {
+        Self { slider, ..self }
+    }
+
+    /// Set to `true` to edit logarithmically.
+    ///
+    /// Sliders use `egui::Slider::logarithmic`. Dragging a `DragValue` changes the value in proportion to its magnitude,
+    /// by roughly a percent per point, instead of by a fixed step.
+    pub fn logarithmic(self, logarithmic: bool) -> Self {
+        Self {
+            logarithmic,
+            ..self
+        }
+    }
+
+    /// Set the smallest positive value that logarithmic editing distinguishes. Below it, the value changes linearly,
+    /// so it can get to and past zero instead of getting stuck close to it.
+    ///
+    /// Defaults to 1 for integers, and 1e-6 for floats.
+    pub fn smallest_positive(self, smallest_positive: f64) -> Self {
+        Self {
+            smallest_positive: Some(smallest_positive),
+            ..self
+        }
+    }
+
+    /// Round the value to the nearest multiple of `step` while dragging, such as 0.25.
+    ///
+    /// Only dragging snaps, so typing in an exact value still works.
+    /// The widget clamps the value to `NumericProperty::range` first, then it is snapped, and then clamped again.
+    /// So a snapped value never leaves the range, but range bounds that are not a multiple of `step` can only be reached by typing.
+    pub fn snap(self, step: T) -> Self {
+        Self {
+            snap: Some(step),
+            ..self
+        }
+    }
+
+    /// Set to `true` to clamp typed values to `NumericProperty::range` as soon as they are entered.
+    ///
+    /// Values that are out of range to begin with, such as ones loaded from a file, are clamped as soon as the property
+    /// is shown. Unlike `NumericProperty::reject_out_of_range`, this silently corrects the value instead of showing an error,
+    /// which suits bounded settings.
+    pub fn clamp_to_range(self, clamp_to_range: bool) -> Self {
+        Self {
+            clamp_to_range,
+            ..self
+        }
+    }
+
+    /// Set to `true` to fail validation with `ValidationError::OutOfRange` while the value is outside of `NumericProperty::range`.
+    ///
+    /// Such values can be typed in, or be out of range to begin with. They are kept, so the user sees what is wrong.
+    /// With `NumericProperty::clamp_to_range`, values never leave the range, so this has no effect.
+    pub fn reject_out_of_range(self, reject_out_of_range: bool) -> Self {
+        Self {
+            reject_out_of_range,
+            ..self
+        }
+    }
+
+    /// Set to `true` to drag ten times faster while shift is held, and ten times slower while alt or ctrl is held.
+    ///
+    /// Without this, egui drags ten times slower while shift is held. Sliders follow the pointer, and are not affected.
+    pub fn precision_modifiers(self, precision_modifiers: bool) -> Self {
+        Self {
+            precision_modifiers,
+            ..self
+        }
+    }
+}
+
+/// The factor of the drag speed for the held modifier keys, see `NumericProperty::precision_modifiers`.
+fn modifier_speed(ui: &Ui) -> f64 {
+    let modifiers = ui.input(|i| i.modifiers);
+    if modifiers.alt || modifiers.ctrl {
+        0.1
+    } else if modifiers.shift_only() {
+        // a DragValue divides by ten itself while shift is held
+        100.0
+    } else if modifiers.shift {
+        10.0
+    } else {
+        1.0
+    }
+}
+
+impl<T> NumericProperty<'_, T> {
+    /// Show and edit the value in a different unit than it is stored in, such as degrees for radians.
+    ///
+    /// The value is shown multiplied by `factor`, followed by `unit`, and typed values are divided by it.
+    /// Dragging a `DragValue` changes the displayed value by about one per point. Everything else, such as `NumericProperty::range` and
+    /// `NumericProperty::snap`, stays in the stored unit.
+    /// ```
+    /// # use egui_property_editor::{NumericProperty, Property};
+    /// # fn example(angle_radians: &mut f32) -> Property<'_> {
+    /// NumericProperty::new(angle_radians)
+    ///     .range(0.0..=std::f32::consts::TAU)
+    ///     .scaled(180.0 / std::f64::consts::PI, "°")
+    ///     .into()
+    /// # }
+    /// ```
+    pub fn scaled(self, factor: f64, unit: impl Into<String>) -> Self {
+        Self {
+            scale: Some((factor, unit.into())),
+            ..self
+        }
+    }
+}
+
+/// Formats a value in the displayed unit, see `NumericProperty::scaled`.
+fn format_scaled(value: f64, decimals: RangeInclusive<usize>, factor: f64, unit: &str) -> String {
+    format!(
+        "{}{unit}",
+        egui::emath::format_with_decimals_in_range(value * factor, decimals)
+    )
+}
+
+/// Parses a value typed in the displayed unit, with or without the unit, back into the stored unit, see `NumericProperty::scaled`.
+fn parse_scaled(text: &str, factor: f64, unit: &str) -> Option<f64> {
+    let text = text.trim();
+    let text = text.strip_suffix(unit.trim()).unwrap_or(text);
+    Some(text.trim().parse::<f64>().ok()? / factor)
+}
+
+impl<'a, T: Numeric + PropertyClipboard> From<NumericProperty<'a, T>> for Property<'a> {
+    fn from(property: NumericProperty<'a, T>) -> Self {
+        Self::from_checked_widget_fn(move |ui| {
+            let NumericProperty {
+                value,
+                range,
+                slider,
+                logarithmic,
+                smallest_positive,
+                snap,
+                clamp_to_range,
+                reject_out_of_range,
+                scale,
+                precision_modifiers,
+            } = property;
+            let speed = if precision_modifiers {
+                modifier_speed(ui)
+            } else {
+                1.0
+            };
+            let scale = scale
+                .as_ref()
+                .map(|(factor, unit)| (*factor, unit.as_str()));
+            let range = range.unwrap_or(T::MIN..=T::MAX);
+            let smallest_positive =
+                smallest_positive.unwrap_or(if T::INTEGRAL { 1.0 } else { 1e-6 });
+            let mut resp = if slider {
+                let mut slider = Slider::new(&mut *value, range.clone())
+                    .logarithmic(logarithmic)
+                    .smallest_positive(smallest_positive)
+                    .clamping(if clamp_to_range {
+                        SliderClamping::Always
+                    } else {
+                        SliderClamping::Never
+                    });
+                if let Some((factor, unit)) = scale {
+                    slider = slider
+                        .drag_value_speed(1.0 / factor)
+                        .custom_formatter(move |value, decimals| {
+                            format_scaled(value, decimals, factor, unit)
+                        })
+                        .custom_parser(move |text| parse_scaled(text, factor, unit));
+                }
+                ui.add(slider)
+            } else if logarithmic {
+                exponential_drag_value(
+                    ui,
+                    &mut *value,
+                    range.clone(),
+                    smallest_positive,
+                    clamp_to_range,
+                    scale,
+                    speed,
+                )
+            } else {
+                let mut drag_value = DragValue::new(&mut *value).speed(speed);
+                // a range also clamps typed values, so without clamping, dragging is kept in range below
+                if clamp_to_range {
+                    drag_value = drag_value.range(range.clone());
+                }
+                if let Some((factor, unit)) = scale {
+                    drag_value = drag_value
+                        .speed(speed / factor)
+                        .custom_formatter(move |value, decimals| {
+                            format_scaled(value, decimals, factor, unit)
+                        })
+                        .custom_parser(move |text| parse_scaled(text, factor, unit));
+                }
+                ui.add(drag_value)
+            };
+            let (min, max) = (range.start().to_f64(), range.end().to_f64());
+            if !clamp_to_range && resp.dragged() && resp.changed() && !range.contains(value) {
+                *value = T::from_f64(value.to_f64().clamp(min, max));
+            }
+            // the widgets keep the precise value while dragging, so small movements still add up
+            if let Some(step) = snap
+                && resp.changed()
+                && (resp.dragged() || resp.drag_stopped())
+            {
+                let step = step.to_f64();
+                if step > 0.0 {
+                    let snapped = ((value.to_f64() / step).round() * step).clamp(min, max);
+                    *value = T::from_f64(snapped);
+                }
+            }
+            value.clipboard_context_menu(&mut resp);
+            let result = if reject_out_of_range && !range.contains(value) {
+                Err(ValidationError::OutOfRange)
+            } else {
+                Ok(())
+            };
+            (resp, result)
+        })
+    }
+}
+
+/// Checks if `url` looks like a URL, that is a scheme followed by something, such as `https://example.com`.
+///
+/// Schemes with hosts, such as http, need one. This is not a full parser, just enough to catch typos.
+fn is_valid_url(url: &str) -> bool {
+    let Some((scheme, rest)) = url.split_once(':') else {
+        return false;
+    };
+    let valid_scheme = scheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !valid_scheme || rest.is_empty() || url.chars().any(char::is_whitespace) {
+        return false;
+    }
+    match scheme.to_ascii_lowercase().as_str() {
+        "http" | "https" | "ftp" | "ws" | "wss" => rest
+            .strip_prefix("//")
+            .and_then(|rest| rest.split(['/', '?', '#']).next())
+            .is_some_and(|host| !host.is_empty()),
+        _ => true,
+    }
+}
+
+/// How many components of an array are shown per row, see `Property::array`.
+const ARRAY_ROW_LENGTH: usize = 4;
+
+/// Adds a `DragValue` for each component of `value`, in rows of `ARRAY_ROW_LENGTH`, and returns their combined response.
+fn array_ui<T: Numeric, const N: usize>(
+    ui: &mut Ui,
+    value: &mut [T; N],
+    labels: Option<[WidgetText; N]>,
+) -> Response {
+    let mut labels = labels.map(|labels| labels.into_iter());
+    ui.vertical(|ui| {
+        let mut resp: Option<Response> = None;
+        for row in value.chunks_mut(ARRAY_ROW_LENGTH) {
+            ui.horizontal(|ui| {
+                for component in row {
+                    if let Some(label) = labels.as_mut().and_then(Iterator::next) {
+                        ui.label(label);
+                    }
+                    let component_resp = ui.add(DragValue::new(component));
+                    resp = Some(match resp.take() {
+                        Some(resp) => resp.union(component_resp),
+                        None => component_resp,
+                    });
+                }
+            });
+        }
+        resp.unwrap_or_else(|| ui.label(""))
+    })
+    .inner
+}
+
+/// Adds a `DragValue` that changes `value` in proportion to its magnitude while dragging.
+///
+/// It drags `asinh(value / smallest_positive)` instead of the value itself. That is logarithmic for large values,
+/// but linear close to zero, so the value can pass through zero.
+fn exponential_drag_value<T: Numeric>(
+    ui: &mut Ui,
+    value: &mut T,
+    range: RangeInclusive<T>,
+    smallest_positive: f64,
+    clamp_to_range: bool,
+    scale: Option<(f64, &str)>,
+    speed: f64,
+) -> Response {
+    let (factor, unit) = scale.unwrap_or((1.0, ""));
+    let to_drag = |value: f64| (value / smallest_positive).asinh();
+    let from_drag = |drag: f64| drag.sinh() * smallest_positive;
+    let (min, max) = (range.start().to_f64(), range.end().to_f64());
+    ui.add(
+        DragValue::from_get_set(|new| {
+            if let Some(new) = new {
+                let new = from_drag(new);
+                // dragging is kept in range by the caller
+                let new = if clamp_to_range {
+                    new.clamp(min, max)
+                } else {
+                    new
+                };
+                *value = T::from_f64(if T::INTEGRAL { new.round() } else { new });
+            }
+            to_drag(value.to_f64())
+        })
+        // about a percent per point
+        .speed(0.01 * speed)
+        .range(if clamp_to_range {
+            to_drag(min)..=to_drag(max)
+        } else {
+            f64::NEG_INFINITY..=f64::INFINITY
+        })
+        .custom_formatter(|drag, _| {
+            let value = from_drag(drag) * factor;
+            if (T::INTEGRAL && factor == 1.0) || value == 0.0 {
+                return format!("{value:.0}{unit}");
+            }
+            // four significant digits
+            let decimals = (3 - value.abs().log10().floor() as i32).clamp(0, 9) as usize;
+            format!("{value:.decimals$}{unit}")
+        })
+        .custom_parser(|text| parse_scaled(text, factor, unit).map(to_drag)),
+    )
+}
+
+/// A helper macro to add `From<T>` to `Property` for `i128` and `u128`, which `egui::DragValue` cannot edit directly.
+macro_rules! wide_int_impl {
+    ($($t:ty),*) => {
+        $(
+            /// The `DragValue` works on an `f64`, so dragging values beyond 2^53 loses precision.
+            /// Typed values are parsed and kept exactly.
+            impl<'a> From<&'a mut $t> for Property<'a> {
+                fn from(value: &'a mut $t) -> Self {
+                    Self::from_widget_fn(|ui| {
+                        let current = *value;
+                        // the exact value of the last parsed text, since it does not survive the trip through f64
+                        let typed = Cell::new(None);
+                        let mut resp = ui.add(
+                            DragValue::from_get_set(|new| {
+                                if let Some(new) = new {
+                                    // `as` saturates, so this clamps to the range of the type
+                                    *value = match typed.get() {
+                                        Some(exact) if exact as f64 == new => exact,
+                                        _ => new as $t,
+                                    };
+                                }
+                                *value as f64
+                            })
+                            .range(<$t>::MIN as f64..=<$t>::MAX as f64)
+                            .max_decimals(0)
+                            .custom_formatter(|val, _| {
+                                if val == current as f64 {
+                                    current.to_string()
+                                } else {
+                                    format!("{val:.0}")
+                                }
+                            })
+                            .custom_parser(|text| {
+                                let exact = text.trim().parse::<$t>().ok();
+                                typed.set(exact);
+                                exact
+                                    .map(|exact| exact as f64)
+                                    .or_else(|| text.trim().parse().ok())
+                            }),
+                        );
+                        value.clipboard_context_menu(&mut resp);
+                        resp
+                    })
+                }
+            }
+        )*
+    };
+}
+
+wide_int_impl!(i128, u128);
+
+/// A helper macro to add `From<T>` to `Property` for `Wrapping<T>` of the integer types.
+///
+/// Dragging or typing past the limits of the type wraps around, like arithmetic on `Wrapping<T>` does.
+macro_rules! wrapping_impl {
+    ($($t:ty),*) => {
+        $(
+            impl PropertyClipboard for Wrapping<$t> {
+                fn to_clipboard(&self) -> String {
+                    self.0.to_clipboard()
+                }
+
+                fn paste_clipboard(&mut self, text: &str) -> bool {
+                    self.0.paste_clipboard(text)
+                }
+            }
+
+            impl<'a> From<&'a mut Wrapping<$t>> for Property<'a> {
+                fn from(value: &'a mut Wrapping<$t>) -> Self {
+                    Self::from_widget_fn(|ui| {
+                        let mut resp = ui.add(
+                            DragValue::from_get_set(|new| {
+                                if let Some(new) = new {
+                                    // casting down from i128 truncates, which is exactly the wrap around
+                                    *value = Wrapping(new.round() as i128 as $t);
+                                }
+                                value.0 as f64
+                            })
+                            .max_decimals(0),
+                        );
+                        value.clipboard_context_menu(&mut resp);
+                        resp
+                    })
+                }
+            }
+        )*
+    };
+}
+
+wrapping_impl!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize);
+
+/// Edits a value that is parsed from text, such as an `IpAddr`, with a single line text edit.
+///
+/// The text is kept in egui memory while it is being edited or does not parse, so the user can fix it.
+/// Until then, `value` keeps its last valid state, and a `ValidationError::CustomWithMessage` with `error_message` is shown.
+fn parsed_text_property<'a, T>(value: &'a mut T, error_message: &'static str) -> Property<'a>
+where
+    T: Copy + PartialEq + Display + FromStr + PropertyClipboard,
+{
+    Property::from_checked_widget_fn(move |ui| {
+        let scratch_id = property_widget_id(ui, "parsed text").with("scratch");
+        let mut text = ui
+            .data(|d| d.get_temp::<String>(scratch_id))
+            .unwrap_or_else(|| value.to_string());
+        let mut resp = ui.add(
+            TextEdit::singleline(&mut text)
+                .min_size(Vec2::X * 125.0)
+                .clip_text(true),
+        );
+        let parsed = text.trim().parse::<T>();
+        if let Ok(parsed) = &parsed {
+            *value = *parsed;
+        }
+        let old = *value;
+        value.clipboard_context_menu(&mut resp);
+        let result = if *value != old {
+            // pasted
+            text = value.to_string();
+            Ok(())
+        } else {
+            parsed
+                .map(|_| ())
+                .map_err(|_| ValidationError::CustomWithMessage(error_message.to_owned()))
+        };
+        if resp.has_focus() || result.is_err() {
+            ui.data_mut(|d| d.insert_temp(scratch_id, text));
+        } else {
+            ui.data_mut(|d| d.remove::<String>(scratch_id));
+        }
+        (resp, result)
+    })
+}
+
+/// Handles both IPv4 and IPv6 addresses.
+impl<'a> From<&'a mut IpAddr> for Property<'a> {
+    fn from(value: &'a mut IpAddr) -> Self {
+        parsed_text_property(value, "Not a valid IP address.")
+    }
+}
+
+/// Handles both IPv4 and IPv6 addresses, such as "127.0.0.1:8080" or "[::1]:8080".
+impl<'a> From<&'a mut SocketAddr> for Property<'a> {
+    fn from(value: &'a mut SocketAddr) -> Self {
+        parsed_text_property(value, "Not a valid socket address, such as 127.0.0.1:8080.")
+    }
+}
+
+/// A helper wrapper to edit the `NonZero*` integer types, such as `NonZeroU32`.
+///
+/// The value is edited with a `DragValue`. By default, dragging or typing zero shows a `ValidationError::OutOfRange`,
+/// and the value keeps its last non-zero state until the user fixes it. Use `NonZeroProperty::clamp` to instead
+/// replace zero with one (or minus one, for negative signed values).
+///
+/// `&mut NonZero*` also converts into a `Property` directly, with the default behaviour.
+pub struct NonZeroProperty<'a, T> {
+    /// The value to edit
+    value: &'a mut T,
+    /// Clamp to one instead of showing an error
+    clamp: bool,
+}
+
+impl<'a, T> NonZeroProperty<'a, T> {
+    /// Creates a new non-zero property, showing an error on zero.
+    pub fn new(value: &'a mut T) -> Self {
+        Self {
+            value,
+            clamp: false,
+        }
+    }
+
+    /// Set to `true` to replace zero with one, instead of showing an error.
+    pub fn clamp(self, clamp: bool) -> Self {
+        Self { clamp, ..self }
+    }
+}
+
+/// A helper macro to add `From<T>` to `Property` for the `NonZero*` types.
+///
+/// `$one` gives the value zero is clamped to, from the old value `$old`.
+macro_rules! non_zero_impl {
+    ($($t:ident($inner:ty) => |$old:ident| $one:expr),* $(,)?) => {
+        $(
+            impl<'a> From<NonZeroProperty<'a, $t>> for Property<'a> {
+                fn from(property: NonZeroProperty<'a, $t>) -> Self {
+                    Self::from_checked_widget_fn(move |ui| {
+                        let value = property.value;
+                        // zero is kept here between frames, so the user can keep editing it
+                        let scratch_id = property_widget_id(ui, "non zero").with("scratch");
+                        let mut scratch: $inner = ui
+                            .data(|d| d.get_temp(scratch_id))
+                            .unwrap_or(value.get());
+                        let resp = ui.add(DragValue::new(&mut scratch));
+                        match <$t>::new(scratch) {
+                            Some(new_value) => {
+                                *value = new_value;
+                                ui.data_mut(|d| d.remove::<$inner>(scratch_id));
+                                (resp, Ok(()))
+                            }
+                            None if property.clamp => {
+                                let $old = value.get();
+                                *value = <$t>::new($one).unwrap_or(*value);
+                                (resp, Ok(()))
+                            }
+                            None => {
+                                ui.data_mut(|d| d.insert_temp(scratch_id, scratch));
+                                (resp, Err(ValidationError::OutOfRange))
+                            }
+                        }
+                    })
+                }
+            }
+
+            impl<'a> From<&'a mut $t> for Property<'a> {
+                fn from(value: &'a mut $t) -> Self {
+                    NonZeroProperty::new(value).into()
+                }
+            }
+        )*
+    };
+}
+
+non_zero_impl!(
+    NonZeroU8(u8) => |_old| 1,
+    NonZeroU16(u16) => |_old| 1,
+    NonZeroU32(u32) => |_old| 1,
+    NonZeroU64(u64) => |_old| 1,
+    NonZeroUsize(usize) => |_old| 1,
+    NonZeroI8(i8) => |old| old.signum(),
+    NonZeroI16(i16) => |old| old.signum(),
+    NonZeroI32(i32) => |old| old.signum(),
+    NonZeroI64(i64) => |old| old.signum(),
+    NonZeroIsize(isize) => |old| old.signum(),
+);
+
+impl<'a> From<&'a mut bool> for Property<'a> {
+    fn from(value: &'a mut bool) -> Self {
+        BoolProperty::new(value).into()
+    }
+}
+
+/// A helper wrapper to edit a `bool` with a checkbox, or an on/off switch.
+///
+/// `&mut bool` uses this, with a checkbox. Switches read better in settings, where a value takes effect right away.
+pub struct BoolProperty<'a> {
+    /// The value to edit
+    value: &'a mut bool,
+    /// If a switch is shown instead of a checkbox
+    toggle: bool,
+}
+
+impl<'a> BoolProperty<'a> {
+    /// Creates a new bool property, shown as a checkbox.
+    pub fn new(value: &'a mut bool) -> Self {
+        Self {
+            value,
+            toggle: false,
+        }
+    }
+
+    /// Set to `true` to show an animated on/off switch instead of a checkbox.
+    pub fn as_toggle(self, toggle: bool) -> Self {
+        Self { toggle, ..self }
+    }
+}
+
+impl<'a> From<BoolProperty<'a>> for Property<'a> {
+    fn from(property: BoolProperty<'a>) -> Self {
+        Self::from_widget_fn(move |ui| {
+            let BoolProperty { value, toggle } = property;
+            let mut resp = if toggle {
+                toggle_switch(ui, value)
+            } else {
+                ui.checkbox(value, "")
+            };
+            value.clipboard_context_menu(&mut resp);
+            resp
+        })
+    }
+}
+
+/// Adds an on/off switch for `on`, with the knob sliding over when it is clicked, see `BoolProperty::as_toggle`.
+fn toggle_switch(ui: &mut Ui, on: &mut bool) -> Response {
+    let size = ui.spacing().interact_size.y * Vec2::new(2.0, 1.0);
+    let (rect, mut resp) = ui.allocate_exact_size(size, Sense::click());
+    if resp.clicked() {
+        *on = !*on;
+        resp.mark_changed();
+    }
+    resp.widget_info(|| WidgetInfo::selected(WidgetType::Checkbox, ui.is_enabled(), *on, ""));
+    if ui.is_rect_visible(rect) {
+        let how_on = ui.ctx().animate_bool_responsive(resp.id, *on);
+        let visuals = ui.style().interact_selectable(&resp, *on);
+        let rect = rect.expand(visuals.expansion);
+        let radius = rect.height() * 0.5;
+        ui.painter().rect(
+            rect,
+            radius,
+            visuals.bg_fill,
+            visuals.bg_stroke,
+            StrokeKind::Inside,
+        );
+        let knob_x = egui::lerp((rect.left() + radius)..=(rect.right() - radius), how_on);
+        ui.painter().circle(
+            Pos2::new(knob_x, rect.center().y),
+            radius * 0.75,
+            visuals.fg_stroke.color,
+            visuals.fg_stroke,
+        );
+    }
+    resp
+}
+
+/// A helper wrapper for an action button, such as "Test connection" or "Clear cache", between the other properties.
+///
+/// The button is drawn in the widget cell, and the name cell shows the name if the property has one.
+/// It edits no value, so it never fails validation and never counts as changed.
+///
+/// ```
+/// # use egui_property_editor::{ButtonProperty, Property, PropertyEditor};
+/// # fn example(ui: &mut egui::Ui, host: &mut String, connected: bool, cache: &mut Vec<String>) {
+/// let mut test_clicked = false;
+/// let mut clear_clicked = false;
+/// PropertyEditor::new("connection")
+///     .named_property("Host", host)
+///     .property(ButtonProperty::new("Test connection", || test_clicked = true).enabled(!connected))
+///     .named_property("Cache", Property::button("Clear", || clear_clicked = true))
+///     .show(ui);
+/// if clear_clicked {
+///     cache.clear();
+/// }
+/// # let _ = test_clicked;
+/// # }
+/// ```
+pub struct ButtonProperty<'a> {
+    /// The text of the button
+    label: WidgetText,
+    /// Called when the button is clicked
+    on_click: Box<dyn FnOnce() + 'a>,
+    /// If the button can be clicked
+    enabled: bool,
+}
+
+impl<'a> ButtonProperty<'a> {
+    /// Creates a new button property that calls `on_click` when the button is clicked.
+    pub fn new(label: impl Into<WidgetText>, on_click: impl FnOnce() + 'a) -> Self {
+        Self {
+            label: label.into(),
+            on_click: Box::new(on_click),
+            enabled: true,
+        }
+    }
+
+    /// Set to `false` to grey the button out, such as while the action is running.
+    pub fn enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+}
+
+impl<'a> From<ButtonProperty<'a>> for Property<'a> {
+    fn from(property: ButtonProperty<'a>) -> Self {
+        Self::from_widget_fn(move |ui| {
+            let ButtonProperty {
+                label,
+                on_click,
+                enabled,
+            } = property;
+            let resp = ui.add_enabled(enabled, Button::new(label));
+            if resp.clicked() {
+                on_click();
+            }
+            resp
+        })
+    }
+}
+
+/// Draws a checkbox, followed by the widget of `T` in the same row if the checkbox is ticked.
+///
+/// Unticking sets the value to `None`, ticking it sets it to `Some(T::default())`.
+/// This is what `From<&mut Option<T>>` uses. It is not a generic impl, since that would recurse into `Option<Option<T>>`.
+fn inline_optional_property<'a, T>(value: &'a mut Option<T>) -> Property<'a>
+where
+    T: Default,
+    &'a mut T: Into<Property<'a>>,
+{
+    let custom_draw_fn = move |ui: &mut Ui, mut row: PropertyRow| -> bool {
+        row.name_cell(ui);
+        let settings = row.settings;
+        let name_text = row.name_text.clone();
+        let (resp, inner) = row.widget_cell(ui, move |ui| {
+            ui.horizontal(move |ui| {
+                let mut cb = value.is_some();
+                let resp = ui.checkbox(&mut cb, "");
+                if cb != value.is_some() {
+                    *value = cb.then(T::default);
+                }
+                let inner = value
+                    .as_mut()
+                    .map(|val| settings.draw_inline(ui, &name_text, val.into()));
+                (resp, inner)
+            })
+            .inner
+        });
+        row.register_widget(&resp);
+        row.description_cell(ui);
+        ui.end_row();
+
+        let (inner_valid, inner_changed) = inner.unwrap_or((true, false));
+        if inner_changed {
+            row.mark_changed();
+        }
+        row.show_validation_result(ui, &resp) && inner_valid
+    };
+    Property::from_custom_draw_fn(Box::new(custom_draw_fn))
+}
+
+/// A helper macro to add `From<&mut Option<T>>` to `Property` for the primitive types.
+///
+/// For anything that needs more than one row, use `Property::new_optional` instead.
+macro_rules! optional_impl {
+    ($($t:ty),*) => {
+        $(
+            impl<'a> From<&'a mut Option<$t>> for Property<'a> {
+                fn from(value: &'a mut Option<$t>) -> Self {
+                    inline_optional_property(value)
+                }
+            }
+        )*
+    };
+}
+
+/// How a `DurationProperty` shows its value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DurationFormat {
+    /// Values below a minute with a unit (such as "5 ms" or "1.5 s"), longer ones as a clock ("hh:mm:ss" or "dd:hh:mm:ss").
+    #[default]
+    Auto,
+    /// Always plain seconds, such as "90.000 s".
+    Seconds,
+    /// Always a clock, "hh:mm:ss". The hours go past 24 instead of adding days.
+    Clock,
+    /// Always a value with the most fitting unit, from "ns" up to "d", such as "1.5 h".
+    UnitValue,
+}
+
+impl DurationFormat {
+    /// The drag speed of the `DragValue` for a value of `secs` seconds.
+    fn speed(self, secs: f64) -> f64 {
+        let magnitude_step = if secs == 0.0 {
+            1.0
+        } else if secs > 1e-9 {
+            10.0f64.powf(secs.log10().floor())
+        } else {
+            1e-9
+        };
+        let step_size = match self {
+            DurationFormat::Auto => {
+                if secs < 60.0 {
+                    magnitude_step
+                } else if secs < 60.0 * 60.0 {
+                    60.0
+                } else if secs < 60.0 * 60.0 * 24.0 {
+                    60.0 * 60.0
+                } else {
+                    60.0 * 60.0 * 24.0
+                }
+            }
+            DurationFormat::Seconds | DurationFormat::UnitValue => magnitude_step,
+            DurationFormat::Clock => magnitude_step.max(1.0),
+        };
+        // adjust for speed
+        step_size / 25.0
+    }
+
+    /// Formats `secs` seconds, with `decimals` decimals where the format has them.
+    fn format(self, secs: f64, decimals: usize) -> String {
+        let whole_secs = secs as u64;
+        match self {
+            DurationFormat::Auto => {
+                if secs < 60.0 {
+                    DurationFormat::UnitValue.format(secs, decimals)
+                } else if secs < 60.0 * 60.0 * 24.0 {
+                    DurationFormat::Clock.format(secs, decimals)
+                } else {
+                    let days = whole_secs / (60 * 60 * 24);
+                    let hours = (whole_secs % (60 * 60 * 24)) / (60 * 60);
+                    let minutes = (whole_secs % (60 * 60)) / 60;
+                    let secs = whole_secs % 60;
+                    format!("{days:0>2}:{hours:0>2}:{minutes:0>2}:{secs:0>2}")
+                }
+            }
+            DurationFormat::Seconds => format!("{secs:.decimals$} s"),
+            DurationFormat::Clock => {
+                let hours = whole_secs / (60 * 60);
+                let minutes = (whole_secs % (60 * 60)) / 60;
+                let secs = whole_secs % 60;
+                format!("{hours:0>2}:{minutes:0>2}:{secs:0>2}")
+            }
+            DurationFormat::UnitValue => {
+                let (multiplier, unit) = if secs == 0.0 {
+                    (1.0, "s")
+                } else if secs < 1e-6 {
+                    (1e9, "ns")
+                } else if secs < 1e-3 {
+                    (1e6, "µs")
+                } else if secs < 1.0 {
+                    (1e3, "ms")
+                } else if secs < 60.0 {
+                    (1.0, "s")
+                } else if secs < 60.0 * 60.0 {
+                    (1.0 / 60.0, "min")
+                } else if secs < 60.0 * 60.0 * 24.0 {
+                    (1.0 / (60.0 * 60.0), "h")
+                } else {
+                    (1.0 / (60.0 * 60.0 * 24.0), "d")
+                };
+                format!("{value:.decimals$} {unit}", value = secs * multiplier)
+            }
+        }
+    }
+}
+
+/// Parses a duration in seconds, from a plain number, a number with a unit (such as "5ms"), or "d:h:m:s".
+///
+/// All `DurationFormat`s accept all of these.
+fn parse_duration_secs(s: &str) -> Option<f64> {
+    // simple case: just a number
+    s.parse::<f64>().ok().or_else(|| {
+        // case two: number + unit
+        let unit_split_pos = s.find(|s: char| {
+            (!s.is_ascii_digit() && s != 'e' && s != '-' && s != '.') || s.is_whitespace()
+        });
+        let result =
+            if let Some((left, right)) = unit_split_pos.and_then(|pos| s.split_at_checked(pos)) {
+                let num = left.trim();
+                let unit = right.trim().to_lowercase();
+                num.parse::<f64>().ok().and_then(|num| match unit.as_str() {
+                    "ns" => Some(num * 1e-9),
+                    "us" | "µs" => Some(num * 1e-6),
+                    "ms" => Some(num * 1e-3),
+                    "" | "s" => Some(num),
+                    "m" | "min" | "minutes" => Some(num * 60.0),
+                    "h" | "hour" | "hours" => Some(num * 60.0 * 60.0),
+                    "d" | "day" | "days" => Some(num * 60.0 * 60.0 * 24.0),
+                    _ => None,
+                })
+            } else {
+                None
+            };
+        // last attempt: a:b:c format
+        result.or_else(|| {
+            let splits: Vec<_> = s.split(":").collect();
+            let (d, h, m, secs) = match splits.len() {
+                2 => (None, None, Some(splits[0]), Some(splits[1])),
+                3 => (None, Some(splits[0]), Some(splits[1]), Some(splits[2])),
+                4 => (
+                    Some(splits[0]),
+                    Some(splits[1]),
+                    Some(splits[2]),
+                    Some(splits[3]),
+                ),
+                _ => return None,
+            };
+            let seconds_d = if let Some(d) = d {
+                d.parse::<f64>().ok()? * 60.0 * 60.0 * 24.0
+            } else {
+                0.0
+            };
+            let seconds_h = if let Some(h) = h {
+                h.parse::<f64>().ok()? * 60.0 * 60.0
+            } else {
+                0.0
+            };
+            let seconds_m = if let Some(m) = m {
+                m.parse::<f64>().ok()? * 60.0
+            } else {
+                0.0
+            };
+            let seconds = if let Some(secs) = secs {
+                secs.parse::<f64>().ok()?
+            } else {
+                0.0
+            };
+            Some(seconds_d + seconds_h + seconds_m + seconds)
+        })
+    })
+}
+
+/// A helper wrapper to edit a `Duration` with a specific `DurationFormat`.
+///
+/// `&mut Duration` also converts into a `Property` directly, with `DurationFormat::Auto`.
+pub struct DurationProperty<'a> {
+    /// The value to edit
+    value: &'a mut Duration,
+    /// How the value is shown
+    format: DurationFormat,
+}
+
+impl<'a> DurationProperty<'a> {
+    /// Creates a new duration property, with `DurationFormat::Auto`.
+    pub fn new(value: &'a mut Duration) -> Self {
+        Self {
+            value,
+            format: DurationFormat::Auto,
+        }
+    }
+
+    /// Set how the value is shown.
+    ///
+    /// Pin a format if the automatic switching between units while dragging is distracting.
+    pub fn format(self, format: DurationFormat) -> Self {
+        Self { format, ..self }
+    }
+}
+
+impl<'a> From<DurationProperty<'a>> for Property<'a> {
+    fn from(property: DurationProperty<'a>) -> Self {
+        Self::from_widget_fn(move |ui| {
+            let DurationProperty { value, format } = property;
+            let mut secs = value.as_secs_f64();
+            let speed = format.speed(secs);
+            let mut resp = DragValue::new(&mut secs)
+                .speed(speed)
+                .max_decimals(3)
+                .range(0.0..=f64::MAX)
+                .custom_formatter(|val, range| format.format(val, range.max().unwrap_or(3)))
+                .custom_parser(parse_duration_secs)
+                .ui(ui)
+                .on_hover_text("Both d:m:h:s and <value> <unit> (such as 1h, 10s, 5ms) are valid.");
+            *value = Duration::from_secs_f64(secs);
+            value.clipboard_context_menu(&mut resp);
+            resp
+        })
+    }
+}
+
+impl<'a> From<&'a mut Duration> for Property<'a> {
+    fn from(value: &'a mut Duration) -> Self {
+        DurationProperty::new(value).into()
+    }
+}
+
+/// A helper wrapper to edit a normalized value, such as 0.25, as a percentage, such as 25%.
+///
+/// The value itself stays normalized, only the display and typed input are in percent.
+/// `Property::percent` is a shorthand for the defaults.
+pub struct PercentProperty<'a> {
+    /// The value to edit
+    value: &'a mut f32,
+    /// The range the normalized value is clamped to
+    range: RangeInclusive<f32>,
+    /// The number of decimals of the percentage
+    decimals: usize,
+}
+
+impl<'a> PercentProperty<'a> {
+    /// Creates a new percent property, from 0% to 100% without decimals.
+    pub fn new(value: &'a mut f32) -> Self {
+        Self {
+            value,
+            range: 0.0..=1.0,
+            decimals: 0,
+        }
+    }
+
+    /// Set the range the normalized value is clamped to, such as `0.0..=2.0` to allow up to 200%.
+    pub fn range(self, range: RangeInclusive<f32>) -> Self {
+        Self { range, ..self }
+    }
+
+    /// Set the number of decimals of the percentage, so 1 shows 12.5%.
+    pub fn decimals(self, decimals: usize) -> Self {
+        Self { decimals, ..self }
+    }
+}
+
+impl<'a> From<PercentProperty<'a>> for Property<'a> {
+    fn from(property: PercentProperty<'a>) -> Self {
+        Self::from_widget_fn(move |ui| {
+            let PercentProperty {
+                value,
+                range,
+                decimals,
+            } = property;
+            // two more decimals in the normalized domain, so nothing is lost when rounding
+            let mut resp = DragValue::new(value)
+                .range(range)
+                .speed(0.001)
+                .fixed_decimals(decimals + 2)
+                .custom_formatter(|val, _| format!("{:.*}%", decimals, val * 100.0))
+                .custom_parser(|text| {
+                    let text = text.trim();
+                    let text = text.strip_suffix('%').unwrap_or(text);
+                    Some(text.trim().parse::<f64>().ok()? / 100.0)
+                })
+                .ui(ui);
+            value.clipboard_context_menu(&mut resp);
+            resp
+        })
+    }
+}
+
+/// A calendar date, edited as year, month and day, for the `SystemTime` property.
+///
+/// This is a plain value, so it can hold dates that do not exist, such as the 30th of February. Those fail validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Date {
+    /// The year, in the proleptic Gregorian calendar.
+    year: i32,
+    /// The month, from 1 to 12.
+    month: u32,
+    /// The day of the month, from 1.
+    day: u32,
+}
+
+impl Default for Date {
+    /// 1970-01-01
+    fn default() -> Self {
+        Self {
+            year: 1970,
+            month: 1,
+            day: 1,
+        }
+    }
+}
+
+impl Date {
+    /// The number of days in `month` of `year`, or `None` if the month does not exist.
+    fn days_in_month(year: i32, month: u32) -> Option<u32> {
+        let leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+            4 | 6 | 9 | 11 => Some(30),
+            2 if leap_year => Some(29),
+            2 => Some(28),
+            _ => None,
+        }
+    }
+
+    /// Checks that this date exists.
+    fn validate(&self) -> Result<(), ValidationError> {
+        let days = Self::days_in_month(self.year, self.month).ok_or(ValidationError::OutOfRange)?;
+        if self.day == 0 || self.day > days {
+            return Err(ValidationError::CustomWithMessage(format!(
+                "{:04}-{:02} only has {days} days.",
+                self.year, self.month
+            )));
+        }
+        Ok(())
+    }
+
+    /// Draws the year, month and day `DragValue`s, and returns their combined response.
+    fn ui(&mut self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            let year = ui.add(
+                DragValue::new(&mut self.year)
+                    .speed(0.1)
+                    .custom_formatter(|val, _| format!("{val:04}")),
+            );
+            ui.label("-");
+            let month = ui.add(
+                DragValue::new(&mut self.month)
+                    .range(1..=12)
+                    .speed(0.05)
+                    .custom_formatter(|val, _| format!("{val:02}")),
+            );
+            ui.label("-");
+            let day = ui.add(
+                DragValue::new(&mut self.day)
+                    .range(1..=31)
+                    .speed(0.1)
+                    .custom_formatter(|val, _| format!("{val:02}")),
+            );
+            year.union(month).union(day)
+        })
+        .inner
+    }
+}
+
+/// A time of day, edited as hours, minutes and seconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+struct TimeOfDay {
+    /// The hour, from 0 to 23.
+    hour: u32,
+    /// The minute, from 0 to 59.
+    minute: u32,
+    /// The second, from 0 to 59.
+    second: u32,
+}
+
+impl TimeOfDay {
+    /// Draws the hour, minute and second `DragValue`s, and returns their combined response.
+    fn ui(&mut self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            let mut parts = [
+                (&mut self.hour, 23),
+                (&mut self.minute, 59),
+                (&mut self.second, 59),
+            ]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (value, max))| {
+                if i > 0 {
+                    ui.label(":");
+                }
+                ui.add(
+                    DragValue::new(value)
+                        .range(0..=max)
+                        .speed(0.1)
+                        .custom_formatter(|val, _| format!("{val:02}")),
+                )
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+            let first = parts.next().expect("three parts");
+            parts.fold(first, |acc, part| acc.union(part))
+        })
+        .inner
+    }
+}
+
+/// A date and a time of day, see `Date` and `TimeOfDay`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+struct DateTime {
+    /// The date
+    date: Date,
+    /// The time of day
+    time: TimeOfDay,
+}
+
+impl DateTime {
+    /// The date and time `seconds` after 1970-01-01 00:00:00 UTC. Negative seconds are before that.
+    fn from_unix_seconds(seconds: i64) -> Self {
+        let days = seconds.div_euclid(86_400);
+        let second_of_day = seconds.rem_euclid(86_400) as u32;
+        // see http://howardhinnant.github.io/date_algorithms.html, the year starts in March there
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        Self {
+            date: Date { year, month, day },
+            time: TimeOfDay {
+                hour: second_of_day / 3600,
+                minute: second_of_day / 60 % 60,
+                second: second_of_day % 60,
+            },
+        }
+    }
+
+    /// The seconds since 1970-01-01 00:00:00 UTC, negative if before that. Call `Date::validate` first, if in doubt.
+    fn to_unix_seconds(self) -> i64 {
+        let month = i64::from(self.date.month);
+        let year = i64::from(self.date.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+        let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(self.date.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146_097 + day_of_era - 719_468;
+        days * 86_400
+            + i64::from(self.time.hour) * 3600
+            + i64::from(self.time.minute) * 60
+            + i64::from(self.time.second)
+    }
+
+    /// Draws the date and the time of day next to each other, and returns their combined response.
+    fn ui(&mut self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| self.date.ui(ui).union(self.time.ui(ui)))
+            .inner
+    }
+}
+
+/// Edited as a `DateTime` in UTC. Sub-second precision is kept, but not shown.
+///
+/// Dates that do not exist, and times before 1970-01-01 00:00:00 UTC, show an error. Until they are fixed,
+/// the edited time is kept in egui memory, and `value` keeps its last valid state.
+impl<'a> From<&'a mut SystemTime> for Property<'a> {
+    fn from(value: &'a mut SystemTime) -> Self {
+        Self::from_checked_widget_fn(|ui| {
+            let scratch_id = property_widget_id(ui, "system time").with("scratch");
+            let (seconds, subsec_nanos) = match value.duration_since(UNIX_EPOCH) {
+                Ok(since) => (since.as_secs() as i64, since.subsec_nanos()),
+                Err(before) => (-(before.duration().as_secs_f64().ceil() as i64), 0),
+            };
+            let mut date_time = ui
+                .data(|d| d.get_temp::<DateTime>(scratch_id))
+                .unwrap_or_else(|| DateTime::from_unix_seconds(seconds));
+            let resp = ui
+                .horizontal(|ui| {
+                    let resp = date_time.ui(ui);
+                    ui.label("UTC");
+                    resp
+                })
+                .inner;
+            let result = date_time.date.validate().and_then(|()| {
+                u64::try_from(date_time.to_unix_seconds()).map_err(|_| {
+                    ValidationError::CustomWithMessage("Before 1970-01-01 00:00:00 UTC".to_owned())
+                })
+            });
+            match result {
+                Ok(seconds) => {
+                    if resp.changed() {
+                        *value = UNIX_EPOCH + Duration::new(seconds, subsec_nanos);
+                    }
+                    ui.data_mut(|d| d.remove_temp::<DateTime>(scratch_id));
+                    (resp, Ok(()))
+                }
+                Err(error) => {
+                    ui.data_mut(|d| d.insert_temp(scratch_id, date_time));
+                    (resp, Err(error))
+                }
+            }
+        })
+    }
+}
+
+/// The fields of a chrono date and time while they are edited, see `calendar_property`.
+///
+/// Unlike the chrono types, this can hold dates that do not exist, such as the 30th of February.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct CalendarFields {
+    /// The year, in the proleptic Gregorian calendar.
+    year: i32,
+    /// The month, from 1 to 12.
+    month: u32,
+    /// The day of the month, from 1.
+    day: u32,
+    /// The hour, from 0 to 23.
+    hour: u32,
+    /// The minute, from 0 to 59.
+    minute: u32,
+    /// The second, from 0 to 59.
+    second: u32,
+}
+
+#[cfg(feature = "chrono")]
+impl CalendarFields {
+    /// The fields of `date` and `time`.
+    fn new(date: chrono::NaiveDate, time: chrono::NaiveTime) -> Self {
+        use chrono::{Datelike, Timelike};
+        Self {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+        }
+    }
+
+    /// The date, or an error if it does not exist.
+    fn date(&self) -> Result<chrono::NaiveDate, ValidationError> {
+        chrono::NaiveDate::from_ymd_opt(self.year, self.month, self.day).ok_or_else(|| {
+            ValidationError::CustomWithMessage(format!(
+                "{:04}-{:02}-{:02} does not exist.",
+                self.year, self.month, self.day
+            ))
+        })
     }
-}
 
-impl<'a> From<&'a mut String> for Property<'a> {
-    fn from(value: &'a mut String) -> Self {
-        Self::from_widget_fn(|ui| {
+    /// The time of day, with the `nanosecond` that is not shown.
+    fn time(&self, nanosecond: u32) -> Result<chrono::NaiveTime, ValidationError> {
+        chrono::NaiveTime::from_hms_nano_opt(self.hour, self.minute, self.second, nanosecond)
+            // a leap second only exists at the end of a minute
+            .or_else(|| chrono::NaiveTime::from_hms_opt(self.hour, self.minute, self.second))
+            .ok_or(ValidationError::OutOfRange)
+    }
+
+    /// Draws the `DragValue`s of the date, the time of day or both, and returns their combined response.
+    fn ui(&mut self, ui: &mut Ui, date: bool, time: bool) -> Response {
+        /// Adds a `DragValue` for `value` in `range`, with two digits.
+        fn two_digits(ui: &mut Ui, value: &mut u32, range: RangeInclusive<u32>) -> Response {
             ui.add(
-                TextEdit::singleline(value)
-                    .min_size(Vec2::X * 125.0)
-                    .clip_text(true),
+                DragValue::new(value)
+                    .range(range)
+                    .speed(0.1)
+                    .custom_formatter(|val, _| format!("{val:02}")),
             )
+        }
+
+        ui.horizontal(|ui| {
+            let mut parts = vec![];
+            if date {
+                parts.push(
+                    ui.add(
+                        DragValue::new(&mut self.year)
+                            .speed(0.1)
+                            .custom_formatter(|val, _| format!("{val:04}")),
+                    ),
+                );
+                ui.label("-");
+                parts.push(two_digits(ui, &mut self.month, 1..=12));
+                ui.label("-");
+                parts.push(two_digits(ui, &mut self.day, 1..=31));
+            }
+            if time {
+                parts.push(two_digits(ui, &mut self.hour, 0..=23));
+                ui.label(":");
+                parts.push(two_digits(ui, &mut self.minute, 0..=59));
+                ui.label(":");
+                parts.push(two_digits(ui, &mut self.second, 0..=59));
+            }
+            parts
+                .into_iter()
+                .reduce(|acc, part| acc.union(part))
+                .expect("a date or a time is drawn")
         })
+        .inner
     }
 }
 
-/// A helper macro to add `From<T>` to `Property` for primitive types that allow `egui::DragValue`.
-macro_rules! numeric_impl {
-    ($t:ty) => {
-        impl<'a> From<&'a mut $t> for Property<'a>
-        {
-            fn from(value: &'a mut $t) -> Self {
-                Self::from_widget_fn(|ui| {
-                    ui.add(DragValue::new(value))
-                })
+/// Edits a chrono value as `CalendarFields`, with the date, the time of day or both.
+///
+/// `to_fields` splits the value up, `to_value` puts the edited fields back together, given the current value.
+/// If that fails, the error is shown, and the fields are kept in egui memory until they are fixed.
+/// Meanwhile, `value` keeps its last valid state.
+#[cfg(feature = "chrono")]
+fn calendar_property<'a, T: 'a>(
+    value: &'a mut T,
+    date: bool,
+    time: bool,
+    to_fields: impl FnOnce(&T) -> CalendarFields + 'a,
+    to_value: impl FnOnce(&CalendarFields, &T) -> Result<T, ValidationError> + 'a,
+) -> Property<'a> {
+    Property::from_checked_widget_fn(move |ui| {
+        let scratch_id = property_widget_id(ui, "calendar").with("scratch");
+        let mut fields = ui
+            .data(|d| d.get_temp::<CalendarFields>(scratch_id))
+            .unwrap_or_else(|| to_fields(value));
+        let resp = fields.ui(ui, date, time);
+        match to_value(&fields, value) {
+            Ok(new_value) => {
+                if resp.changed() {
+                    *value = new_value;
+                }
+                ui.data_mut(|d| d.remove_temp::<CalendarFields>(scratch_id));
+                (resp, Ok(()))
+            }
+            Err(error) => {
+                ui.data_mut(|d| d.insert_temp(scratch_id, fields));
+                (resp, Err(error))
             }
         }
-    };
-    ($t:tt,$($rest:tt)*) => {
-        numeric_impl!($t);
-        numeric_impl!($($rest)*);
+    })
+}
+
+/// Edited as year, month and day. Dates that do not exist, such as the 30th of February, show an error.
+#[cfg(feature = "chrono")]
+impl<'a> From<&'a mut chrono::NaiveDate> for Property<'a> {
+    fn from(value: &'a mut chrono::NaiveDate) -> Self {
+        calendar_property(
+            value,
+            true,
+            false,
+            |date| CalendarFields::new(*date, chrono::NaiveTime::default()),
+            |fields, _| fields.date(),
+        )
     }
 }
 
-numeric_impl!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, f32, f64);
+/// Edited as hours, minutes and seconds. Sub-second precision is kept, but not shown.
+#[cfg(feature = "chrono")]
+impl<'a> From<&'a mut chrono::NaiveTime> for Property<'a> {
+    fn from(value: &'a mut chrono::NaiveTime) -> Self {
+        use chrono::Timelike;
+        calendar_property(
+            value,
+            false,
+            true,
+            |time| CalendarFields::new(chrono::NaiveDate::default(), *time),
+            |fields, time| fields.time(time.nanosecond()),
+        )
+    }
+}
 
-impl<'a> From<&'a mut bool> for Property<'a> {
-    fn from(value: &'a mut bool) -> Self {
-        Self::from_widget_fn(|ui| ui.checkbox(value, ""))
+/// Edited as a date and a time of day, see the `NaiveDate` and `NaiveTime` properties.
+#[cfg(feature = "chrono")]
+impl<'a> From<&'a mut chrono::NaiveDateTime> for Property<'a> {
+    fn from(value: &'a mut chrono::NaiveDateTime) -> Self {
+        use chrono::Timelike;
+        calendar_property(
+            value,
+            true,
+            true,
+            |date_time| CalendarFields::new(date_time.date(), date_time.time()),
+            |fields, date_time| {
+                Ok(chrono::NaiveDateTime::new(
+                    fields.date()?,
+                    fields.time(date_time.nanosecond())?,
+                ))
+            },
+        )
     }
 }
 
-impl<'a> From<&'a mut Duration> for Property<'a> {
-    fn from(value: &'a mut Duration) -> Self {
+optional_impl!(
+    u8, i8, u16, i16, u32, i32, u64, i64, i128, u128, usize, isize, f32, f64, String, Duration
+);
+
+/// A tri-state checkbox, for settings such as inherit, on and off.
+///
+/// `None` shows a dash. Clicking cycles from `None` to `Some(true)` to `Some(false)`, and back to `None`.
+/// Earlier versions drew a checkbox for `Some`, followed by one for the value, see `Property` for the alternative.
+impl<'a> From<&'a mut Option<bool>> for Property<'a> {
+    fn from(value: &'a mut Option<bool>) -> Self {
         Self::from_widget_fn(|ui| {
-            let mut secs = value.as_secs_f64();
-            let step_size = if secs < 60.0 {
-                if secs == 0.0 {
-                    1.0
-                } else if secs > 1e-9 {
-                    10.0f64.powf(secs.log10().floor())
-                } else {
-                    1e-9
-                }
-            } else if secs < 60.0 * 60.0 {
-                60.0
-            } else if secs < 60.0 * 60.0 * 24.0 {
-                60.0 * 60.0
-            } else {
-                60.0 * 60.0 * 24.0
+            // the checkbox toggles this itself, but the cycle is handled below
+            let mut checked = *value == Some(true);
+            let resp = ui.add(Checkbox::without_text(&mut checked).indeterminate(value.is_none()));
+            if resp.clicked() {
+                *value = match *value {
+                    None => Some(true),
+                    Some(true) => Some(false),
+                    Some(false) => None,
+                };
+            }
+            resp
+        })
+    }
+}
+
+/// The ranges a `RangeProperty` can edit.
+enum RangeRef<'a, T> {
+    /// `start..=end`
+    Inclusive(&'a mut RangeInclusive<T>),
+    /// `start..end`
+    Exclusive(&'a mut Range<T>),
+}
+
+/// A helper wrapper to edit a `RangeInclusive<T>` or `Range<T>` with two `DragValue`s, one for the start and one for the end.
+///
+/// If the user drags the start past the end (or the other way round), the two are swapped.
+/// Use `RangeProperty::clamp_order` to clamp the edited value to the other one instead.
+///
+/// `&mut RangeInclusive<T>` and `&mut Range<T>` also convert into a `Property` directly, with the default behaviour.
+pub struct RangeProperty<'a, T> {
+    /// The range to edit
+    range: RangeRef<'a, T>,
+    /// Clamp instead of swap if start and end are inverted
+    clamp_order: bool,
+}
+
+impl<T> RangeProperty<'_, T> {
+    /// Set to `true` to clamp the edited bound to the other one, instead of swapping them when inverted.
+    pub fn clamp_order(self, clamp_order: bool) -> Self {
+        Self {
+            clamp_order,
+            ..self
+        }
+    }
+}
+
+impl<'a, T> From<&'a mut RangeInclusive<T>> for RangeProperty<'a, T> {
+    fn from(value: &'a mut RangeInclusive<T>) -> Self {
+        Self {
+            range: RangeRef::Inclusive(value),
+            clamp_order: false,
+        }
+    }
+}
+
+impl<'a, T> From<&'a mut Range<T>> for RangeProperty<'a, T> {
+    fn from(value: &'a mut Range<T>) -> Self {
+        Self {
+            range: RangeRef::Exclusive(value),
+            clamp_order: false,
+        }
+    }
+}
+
+impl<'a, T: Numeric> From<RangeProperty<'a, T>> for Property<'a> {
+    fn from(value: RangeProperty<'a, T>) -> Self {
+        Self::from_widget_fn(move |ui| {
+            let (mut start, mut end) = match &value.range {
+                RangeRef::Inclusive(range) => (*range.start(), *range.end()),
+                RangeRef::Exclusive(range) => (range.start, range.end),
             };
-            // adjust for speed
-            let speed = step_size / 25.0;
-            let resp = DragValue::new(&mut secs)
-                .speed(speed)
-                .max_decimals(3)
-                .range(0.0..=f64::MAX)
-                .custom_formatter(|val, range| {
-                    let decimals = range.max().unwrap_or(3);
-                    // seconds and below
-                    if val < 60.0 {
-                        let (multiplier, unit) = if val == 0.0 {
-                            (1.0, "s")
-                        } else if val < 1e-6 {
-                            (1e9, "ns")
-                        } else if val < 1e-3 {
-                            (1e6, "µs")
-                        } else if val < 1.0 {
-                            (1e3, "ms")
-                        } else {
-                            (1.0, "s")
-                        };
-                        format!(
-                            "{value:.prec$} {unit}",
-                            value = val * multiplier,
-                            prec = decimals
-                        )
-                    } else if val < 60.0 * 60.0 * 24.0 {
-                        let hours = value.as_secs() / (60 * 60);
-                        let minutes = (value.as_secs() % (60 * 60)) / 60;
-                        let secs = value.as_secs() % 60;
-                        format!("{hours:0>2}:{minutes:0>2}:{secs:0>2}")
-                    } else {
-                        let days = value.as_secs() / (60 * 60 * 24);
-                        let hours = (value.as_secs() % (60 * 60 * 24)) / (60 * 60);
-                        let minutes = (value.as_secs() % (60 * 60)) / 60;
-                        let secs = value.as_secs() % 60;
-                        format!("{days:0>2}:{hours:0>2}:{minutes:0>2}:{secs:0>2}")
-                    }
-                })
-                .custom_parser(|s| {
-                    // simple case: just a number
-                    s.parse::<f64>().ok().or_else(|| {
-                        // case two: number + unit
-                        let unit_split_pos = s.find(|s: char| {
-                            (!s.is_ascii_digit() && s != 'e' && s != '-' && s != '.')
-                                || s.is_whitespace()
-                        });
-                        let result = if let Some((left, right)) =
-                            unit_split_pos.and_then(|pos| s.split_at_checked(pos))
-                        {
-                            let num = left.trim();
-                            let unit = right.trim().to_lowercase();
-                            num.parse::<f64>().ok().and_then(|num| match unit.as_str() {
-                                "ns" => Some(num * 1e-9),
-                                "us" | "µs" => Some(num * 1e-6),
-                                "ms" => Some(num * 1e-3),
-                                "" | "s" => Some(num),
-                                "m" | "min" | "minutes" => Some(num * 60.0),
-                                "h" | "hour" | "hours" => Some(num * 60.0 * 60.0),
-                                "d" | "day" | "days" => Some(num * 60.0 * 60.0 * 24.0),
-                                _ => None,
-                            })
-                        } else {
-                            None
-                        };
-                        // last attempt: a:b:c format
-                        result.or_else(|| {
-                            let splits: Vec<_> = s.split(":").collect();
-                            let (d, h, m, secs) = match splits.len() {
-                                2 => (None, None, Some(splits[0]), Some(splits[1])),
-                                3 => (None, Some(splits[0]), Some(splits[1]), Some(splits[2])),
-                                4 => (
-                                    Some(splits[0]),
-                                    Some(splits[1]),
-                                    Some(splits[2]),
-                                    Some(splits[3]),
-                                ),
-                                _ => return None,
-                            };
-                            let seconds_d = if let Some(d) = d {
-                                d.parse::<f64>().ok()? * 60.0 * 60.0 * 24.0
-                            } else {
-                                0.0
-                            };
-                            let seconds_h = if let Some(h) = h {
-                                h.parse::<f64>().ok()? * 60.0 * 60.0
-                            } else {
-                                0.0
-                            };
-                            let seconds_m = if let Some(m) = m {
-                                m.parse::<f64>().ok()? * 60.0
-                            } else {
-                                0.0
-                            };
-                            let seconds = if let Some(secs) = secs {
-                                secs.parse::<f64>().ok()?
-                            } else {
-                                0.0
-                            };
-                            Some(seconds_d + seconds_h + seconds_m + seconds)
-                        })
-                    })
+            let (start_resp, end_resp) = ui
+                .horizontal(|ui| {
+                    let start_resp = ui.add(DragValue::new(&mut start));
+                    ui.label("–");
+                    (start_resp, ui.add(DragValue::new(&mut end)))
                 })
-                .ui(ui)
-                .on_hover_text("Both d:m:h:s and <value> <unit> (such as 1h, 10s, 5ms) are valid.");
-            *value = Duration::from_secs_f64(secs);
-            resp
+                .inner;
+            if start > end {
+                if !value.clamp_order {
+                    std::mem::swap(&mut start, &mut end);
+                } else if start_resp.changed() {
+                    start = end;
+                } else {
+                    end = start;
+                }
+            }
+            match value.range {
+                RangeRef::Inclusive(range) => *range = start..=end,
+                RangeRef::Exclusive(range) => *range = start..end,
+            }
+            start_resp.union(end_resp)
         })
     }
 }
 
+impl<'a, T: Numeric> From<&'a mut RangeInclusive<T>> for Property<'a> {
+    fn from(value: &'a mut RangeInclusive<T>) -> Self {
+        RangeProperty::from(value).into()
+    }
+}
+
+impl<'a, T: Numeric> From<&'a mut Range<T>> for Property<'a> {
+    fn from(value: &'a mut Range<T>) -> Self {
+        RangeProperty::from(value).into()
+    }
+}
+
 impl<'a, 'b, T> From<ValidatedProperty<'a, T>> for Property<'b>
 where
     Property<'b>: From<T>,
@@ -827,6 +6722,19 @@ where
     }
 }
 
+impl<'a, T: 'a> From<TypedProperty<'a, T>> for Property<'a>
+where
+    &'a mut T: Into<Property<'a>>,
+{
+    fn from(property: TypedProperty<'a, T>) -> Self {
+        let (value, validation_result) = property.evaluate();
+        Self {
+            validation_result,
+            ..value.into()
+        }
+    }
+}
+
 impl<'a, T, N> From<(N, T)> for Property<'a>
 where
     T: Into<Property<'a>>,
@@ -849,6 +6757,8 @@ where
 }
 
 /// If you have an Option<String>, and want empty strings to result in None, you can use this helper wrapper.
+///
+/// For a `String` that must not be empty, use `RequiredString` instead.
 pub struct EmptyStringIsNone<'a>(pub &'a mut Option<String>);
 
 impl<'a> From<EmptyStringIsNone<'a>> for Property<'a> {
@@ -869,61 +6779,66 @@ impl<'a> From<EmptyStringIsNone<'a>> for Property<'a> {
     }
 }
 
+/// If you have a `String` that must not be empty, such as a required form field, you can use this helper wrapper.
+///
+/// The text edit has a button to clear it. An empty value fails validation with "Required", instead of turning into
+/// `None` like `EmptyStringIsNone`, so the user sees what is missing.
+pub struct RequiredString<'a>(pub &'a mut String);
+
+impl<'a> From<RequiredString<'a>> for Property<'a> {
+    fn from(value: RequiredString<'a>) -> Self {
+        Property::from_checked_widget_fn(|ui| {
+            let value = value.0;
+            let response = ui
+                .horizontal(|ui| {
+                    let mut resp = TextEdit::singleline(value)
+                        .clip_text(true)
+                        .min_size(Vec2::X * 125.0)
+                        .ui(ui);
+                    value.clipboard_context_menu(&mut resp);
+                    if !value.is_empty() && ui.small_button("✖").on_hover_text("Clear").clicked()
+                    {
+                        value.clear();
+                        resp.request_focus();
+                        resp.mark_changed();
+                    }
+                    resp
+                })
+                .inner;
+            if value.is_empty() {
+                return (
+                    response,
+                    Err(ValidationError::CustomWithMessage("Required".into())),
+                );
+            }
+            (response, Ok(()))
+        })
+    }
+}
+
 /// The default validation function just validates to `Ok(())`
 fn default_validation_cb<T>(_val: &T) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// A widget callback that also returns a validation result, see `Property::from_checked_widget_fn`.
+type CheckedWidgetFn<'a> = dyn FnOnce(&mut Ui) -> (Response, Result<(), ValidationError>) + 'a;
+
 /// To reduce generated code, this is the default drawing of the widgets, as a free function.
 fn default_property_draw_fn(
     ui: &mut Ui,
-    name: Option<WidgetText>,
-    description: Option<WidgetText>,
-    validation_result: Result<(), ValidationError>,
-    draw_description: bool,
-    widget_cb: Box<PropertyWidgetFn<'_>>,
+    row: &mut PropertyRow,
+    widget_cb: Box<CheckedWidgetFn<'_>>,
 ) -> bool {
-    if let Some(name) = name {
-        ui.label(name);
-    } else {
-        ui.label("");
-    }
-
-    let resp = widget_cb(ui);
-
-    if draw_description {
-        if let Some(description) = description {
-            ui.with_layout(Layout::top_down(Align::Min), |ui| {
-                ui.style_mut().wrap_mode = Some(TextWrapMode::Wrap);
-                ui.label(description)
-            });
-        } else {
-            ui.label("");
-        }
-    }
-
+    row.name_cell(ui);
+    let (resp, widget_result) = row.widget_cell(ui, widget_cb);
+    row.validation_result =
+        std::mem::replace(&mut row.validation_result, Ok(())).and(widget_result);
+    row.register_widget(&resp);
+    row.description_cell(ui);
     ui.end_row();
 
-    match validation_result {
-        Err(e) => {
-            ui.painter().rect_stroke(
-                resp.interact_rect,
-                1,
-                Stroke::new(2.0, Color32::DARK_RED),
-                StrokeKind::Outside,
-            );
-            ui.painter().text(
-                resp.interact_rect.right_center(),
-                Align2::RIGHT_CENTER,
-                "?",
-                FontId::monospace(resp.interact_rect.height() * 0.9),
-                Color32::DARK_RED,
-            );
-            resp.on_hover_text(e.to_string());
-            false
-        }
-        Ok(_) => true,
-    }
+    row.show_validation_result(ui, &resp)
 }
 
 /// A helper macro to generate a property for a unit enum.
@@ -958,27 +6873,66 @@ fn default_property_draw_fn(
 ///     UnitEnum::B,
 /// );
 /// ```
+///
+/// # Custom display function
+/// Like with `enum_property!`, you can put a display function in front of the variants, followed by a `;` instead of a comma.
+/// It is used for the selected text and the options, instead of `Display`. It must be a free function or a trait method, not a closure.
+/// ```
+/// # use egui_property_editor::unit_enum_property;
+/// #[derive(Debug)]
+/// enum UnitEnum {
+///     A,
+///     B
+/// }
+///
+/// fn lowercase(value: &UnitEnum) -> String {
+///     format!("{value:?}").to_lowercase()
+/// }
+///
+/// let mut my_enum = UnitEnum::A;
+/// let _property = unit_enum_property!(my_enum, lowercase; UnitEnum::A, UnitEnum::B);
+/// ```
 #[macro_export]
 macro_rules! unit_enum_property {
     ($value:expr, $($name:path),+ $(,)?) => {
-         unit_enum_property!(@inner $value, $([$name]),*)
+         unit_enum_property!(@inner $value, std::string::ToString::to_string, $([$name]),*)
     };
-    (@inner $value:expr, $([$($name_tt:tt)*]),*) => {
-        $crate::Property::from_widget_fn(|ui| {
+    ($value:expr, $display_fn:expr; $($name:path),+ $(,)?) => {
+         unit_enum_property!(@inner $value, $display_fn, $([$name]),*)
+    };
+    (@inner $value:expr, $display_fn:expr, $([$($name_tt:tt)*]),*) => {
+        $crate::Property::from_custom_draw_fn(Box::new(|ui, mut row: $crate::PropertyRow| {
             // we need a way to arbitrarily take both references and values.
             use std::ops::DerefMut;
             let mut value = &mut $value;
             let value = value.deref_mut();
-            egui::ComboBox::new(ui.next_auto_id(),"")
-            .selected_text(value.to_string())
+            row.name_cell(ui);
+
+            let mut search = row.combo_search([$(stringify!($($name_tt)*)),*].len());
+            let mut changed = false;
+            let resp = row.widget_cell(ui, |ui| egui::ComboBox::new($crate::property_widget_id(ui, "enum variant"),"")
+            .selected_text($display_fn(value))
             .show_ui(ui,|ui| {
+                search.show(ui);
                 $(
-                    if ui.selectable_label(matches!(value,$($name_tt)*),$($name_tt)*.to_string()).clicked() {
+                    // converted once, so display functions returning `RichText` can be searched too
+                    let name: egui::WidgetText = ($display_fn)(&$($name_tt)*).into();
+                    if search.matches(name.text()) && ui.selectable_label(matches!(value,$($name_tt)*),name).clicked() {
+                        changed |= !matches!(value,$($name_tt)*);
                         *value = $($name_tt)*;
                     };
                 )*
-            }).response
-        })
+            }).response);
+            row.register_widget(&resp);
+            if changed {
+                row.mark_changed();
+            }
+
+            row.description_cell(ui);
+            ui.end_row();
+
+            row.show_validation_result(ui, &resp)
+        }))
     };
 }
 
@@ -994,6 +6948,9 @@ macro_rules! unit_enum_property {
 ///
 /// Take a look at `examples/demo.rs`, where i tried to show why you'd wanna use that.
 ///
+/// # Variants known at runtime
+/// The macro needs the variants at compile time. If they are generated at runtime, use `EnumProperty` instead.
+///
 /// # Example with syntax explaination
 /// ```rust
 /// # use egui_property_editor::enum_property;
@@ -1051,20 +7008,19 @@ macro_rules! enum_property {
         properties: $property_block:block$(;)?
     }
     ),+ $(,)*) => {
-        $crate::Property::from_custom_draw_fn(Box::new(|ui,name,description,validation_result,include_description| {
+        $crate::Property::from_custom_draw_fn(Box::new(|ui, mut row: $crate::PropertyRow| {
             // we need a way to arbitrarily take both references and values.
             use std::ops::DerefMut;
             let mut value = &mut $value;
             let value = value.deref_mut();
-            if let Some(name) = name {
-                ui.label(name);
-            } else {
-                ui.label("");
-            }
+            row.name_cell(ui);
 
-            egui::ComboBox::new(ui.next_auto_id(),"")
+            let mut search = row.combo_search([$(stringify!($default)),+].len());
+            let mut changed = false;
+            let resp = row.widget_cell(ui, |ui| egui::ComboBox::new($crate::property_widget_id(ui, "enum variant"),"")
             .selected_text($display_fn(value))
             .show_ui(ui,|ui| {
+                search.show(ui);
                 $(
                     {
                         let checked = match value {
@@ -1072,24 +7028,24 @@ macro_rules! enum_property {
                             $name => true,
                             _ => false,
                         };
-                        let name = $display_fn(&$default);
-                        if ui.selectable_label(checked,name).clicked() {
+                        // converted once, so display functions returning `RichText` can be searched too
+                        let name: egui::WidgetText = ($display_fn)(&$default).into();
+                        if search.matches(name.text()) && ui.selectable_label(checked,name).clicked() {
                             // do not reset if we click on an already clicked one
                             if !checked {
-                                *value = $default
+                                *value = $default;
+                                changed = true;
                             }
                         }
                     }
                 )*
-            });
-
-            if include_description {
-                if let Some(description) = description {
-                    ui.label(description);
-                } else {
-                    ui.label("");
-                }
+            }).response);
+            row.register_widget(&resp);
+            if changed {
+                row.mark_changed();
             }
+
+            row.description_cell(ui);
             ui.end_row();
 
             let p_list : $crate::PropertyList = match value {
@@ -1097,12 +7053,230 @@ macro_rules! enum_property {
                 _ => vec![],
             };
 
-            let mut valid = true;
+            let mut valid = row.show_validation_result(ui, &resp);
             for property in p_list {
-                valid &= property.draw(ui,include_description);
+                valid &= row.draw_child(ui, property);
             }
 
             valid
         }))
     };
 }
+
+/// One variant of an `EnumProperty`.
+pub struct EnumVariant<'a, T> {
+    /// Name shown in the dropdown
+    name: String,
+    /// Checks if a value is this variant
+    matches: Box<dyn Fn(&T) -> bool + 'a>,
+    /// Value to use when picking this variant in the dropdown
+    default: Box<dyn Fn() -> T + 'a>,
+    /// Generates the child properties when the value is this variant
+    properties: Box<dyn FnOnce(&'a mut T) -> PropertyList<'a> + 'a>,
+}
+
+impl<'a, T> EnumVariant<'a, T> {
+    /// Creates a new variant without child properties.
+    ///
+    /// `matches` tells if a value is this variant, and `default` creates the value that is used when picking it in the dropdown.
+    pub fn new(
+        name: impl Into<String>,
+        matches: impl Fn(&T) -> bool + 'a,
+        default: impl Fn() -> T + 'a,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            matches: Box::new(matches),
+            default: Box::new(default),
+            properties: Box::new(|_| vec![]),
+        }
+    }
+
+    /// Set the callback that generates the child properties while the value is this variant.
+    pub fn properties(self, properties: impl FnOnce(&'a mut T) -> PropertyList<'a> + 'a) -> Self {
+        Self {
+            properties: Box::new(properties),
+            ..self
+        }
+    }
+}
+
+/// The search field at the top of an enum dropdown, see `PropertyRow::combo_search`.
+pub struct ComboSearch {
+    /// Where the filter text is kept in temp data. `None` if the dropdown has no search field.
+    id: Option<Id>,
+    /// The lowercase filter text of this frame
+    filter: String,
+}
+
+impl ComboSearch {
+    /// Draws the search field, if the dropdown has one. Call this first thing in `ComboBox::show_ui`.
+    ///
+    /// The filter text is cleared and the field focused whenever the dropdown opens.
+    pub fn show(&mut self, ui: &mut Ui) {
+        let Some(id) = self.id else {
+            return;
+        };
+        let pass = ui.ctx().cumulative_pass_nr();
+        // the text and the pass it was last shown in, to tell when the dropdown opens again
+        let stored = ui.data(|d| d.get_temp::<(String, u64)>(id));
+        let opened = stored
+            .as_ref()
+            .is_none_or(|(_, last_pass)| last_pass + 1 < pass);
+        let mut text = stored
+            .filter(|_| !opened)
+            .map(|(text, _)| text)
+            .unwrap_or_default();
+        let resp = TextEdit::singleline(&mut text)
+            .id(id)
+            .hint_text("Search")
+            .desired_width(ui.available_width())
+            .show(ui)
+            .response;
+        if opened {
+            resp.request_focus();
+        }
+        ui.separator();
+        self.filter = text.to_lowercase();
+        ui.data_mut(|d| d.insert_temp(id, (text, pass)));
+    }
+
+    /// If a variant with the display string `name` passes the filter.
+    pub fn matches(&self, name: &str) -> bool {
+        self.filter.is_empty() || name.to_lowercase().contains(&self.filter)
+    }
+}
+
+/// The runtime counterpart of `enum_property!`.
+///
+/// Use this if the variants are not known at compile time, such as enums provided by plugins.
+/// It draws the same dropdown, followed by the child properties of the selected variant.
+///
+/// # Example
+/// ```rust
+/// # use egui_property_editor::{EnumProperty, EnumVariant, Property};
+/// enum Shape {
+///     Point,
+///     Circle(f32),
+/// }
+///
+/// let mut shape = Shape::Point;
+/// let property: Property = EnumProperty::new(
+///     &mut shape,
+///     vec![
+///         EnumVariant::new("Point", |s| matches!(s, Shape::Point), || Shape::Point),
+///         EnumVariant::new("Circle", |s| matches!(s, Shape::Circle(_)), || Shape::Circle(1.0))
+///             .properties(|s| match s {
+///                 Shape::Circle(radius) => vec![("Radius", radius).into()],
+///                 _ => vec![],
+///             }),
+///     ],
+/// )
+/// .into();
+/// ```
+pub struct EnumProperty<'a, T> {
+    /// The value to edit
+    value: &'a mut T,
+    /// The selectable variants
+    variants: Vec<EnumVariant<'a, T>>,
+    /// Sums up the value next to the dropdown
+    summary_fn: Option<Box<SummaryFn<'a, T>>>,
+}
+
+impl<'a, T> EnumProperty<'a, T> {
+    /// Creates a new enum property with the given variants.
+    pub fn new(value: &'a mut T, variants: Vec<EnumVariant<'a, T>>) -> Self {
+        Self {
+            value,
+            variants,
+            summary_fn: None,
+        }
+    }
+
+    /// Add a variant.
+    pub fn variant(mut self, variant: EnumVariant<'a, T>) -> Self {
+        self.variants.push(variant);
+        self
+    }
+
+    /// Set a function that sums up the value in a line of text, drawn next to the dropdown.
+    ///
+    /// Use this to show the key fields of the selected variant, such as "r = 1.5" for a circle,
+    /// or to fill the row of a variant without child properties. It is called with the current value each frame.
+    pub fn summary_fn(self, summary_fn: impl Fn(&T) -> WidgetText + 'a) -> Self {
+        Self {
+            summary_fn: Some(Box::new(summary_fn)),
+            ..self
+        }
+    }
+}
+
+impl<'a, T: 'a> From<EnumProperty<'a, T>> for Property<'a> {
+    fn from(property: EnumProperty<'a, T>) -> Self {
+        Self::from_custom_draw_fn(Box::new(move |ui, mut row| {
+            let EnumProperty {
+                value,
+                variants,
+                summary_fn,
+            } = property;
+            row.name_cell(ui);
+
+            let selected = variants.iter().position(|variant| (variant.matches)(value));
+            let mut search = row.combo_search(variants.len());
+            let mut changed = false;
+            let resp = row.widget_cell(ui, |ui| {
+                let mut combo = |ui: &mut Ui, value: &mut T| {
+                    ComboBox::new(property_widget_id(ui, "enum variant"), "")
+                        .selected_text(selected.map_or("", |i| variants[i].name.as_str()))
+                        .show_ui(ui, |ui| {
+                            search.show(ui);
+                            for (i, variant) in variants.iter().enumerate() {
+                                if !search.matches(&variant.name) {
+                                    continue;
+                                }
+                                let checked = selected == Some(i);
+                                // do not reset if we click on an already selected one
+                                if ui.selectable_label(checked, &variant.name).clicked() && !checked
+                                {
+                                    *value = (variant.default)();
+                                    changed = true;
+                                }
+                            }
+                        })
+                        .response
+                };
+                let Some(summary_fn) = &summary_fn else {
+                    return combo(ui, value);
+                };
+                ui.horizontal(|ui| {
+                    let resp = combo(ui, value);
+                    ui.label(summary_fn(value));
+                    resp
+                })
+                .inner
+            });
+            row.register_widget(&resp);
+            if changed {
+                row.mark_changed();
+            }
+
+            row.description_cell(ui);
+            ui.end_row();
+
+            let p_list = match variants
+                .into_iter()
+                .find(|variant| (variant.matches)(value))
+            {
+                Some(variant) => (variant.properties)(value),
+                None => vec![],
+            };
+
+            let mut valid = row.show_validation_result(ui, &resp);
+            for property in p_list {
+                valid &= row.draw_child(ui, property);
+            }
+
+            valid
+        }))
+    }
+}